@@ -147,11 +147,100 @@ fn main() {
         "mujoco/src/user/user_init.c",
     ]);
 
+    // Unwind tables let Rust's error handler unwind through MuJoCo's C frames
+    c_cfg.flag_if_supported("-fexceptions");
     c_cfg.warnings(false);
     c_cfg.compile("mujoco_c");
 }
 
+/// Link to a MuJoCo installed on the system
+///
+/// The library is looked up, in order, in:
+/// - `MUJOCO_LIB_DIR` (with headers in `MUJOCO_INCLUDE_DIR`, or `MUJOCO_DIR/include`)
+/// - `MUJOCO_DIR`, laid out like the official release archives (`lib/`, `include/`)
+/// - pkg-config (`mujoco.pc`)
 #[cfg(not(feature = "vendored-mujoco"))]
 fn main() {
-    todo!("Link to system-installed MuJoCo");
+    use std::path::PathBuf;
+
+    for var in ["MUJOCO_DIR", "MUJOCO_LIB_DIR", "MUJOCO_INCLUDE_DIR"] {
+        println!("cargo:rerun-if-env-changed={var}");
+    }
+    println!("cargo:rerun-if-changed=src/lib.rs");
+
+    let env_path = |var: &str| std::env::var_os(var).map(PathBuf::from);
+    let mujoco_dir = env_path("MUJOCO_DIR");
+
+    let (lib_dirs, include_dirs) = if let Some(lib_dir) = env_path("MUJOCO_LIB_DIR") {
+        let include_dir = env_path("MUJOCO_INCLUDE_DIR")
+            .or_else(|| mujoco_dir.as_ref().map(|dir| dir.join("include")))
+            .or_else(|| lib_dir.parent().map(|dir| dir.join("include")));
+        (vec![lib_dir], include_dir.into_iter().collect())
+    } else if let Some(dir) = mujoco_dir {
+        (vec![dir.join("lib")], vec![dir.join("include")])
+    } else {
+        match pkg_config::Config::new()
+            .cargo_metadata(false)
+            .probe("mujoco")
+        {
+            Ok(library) => (library.link_paths, library.include_paths),
+            Err(e) => panic!(
+                "Could not find a system MuJoCo: {e}\n\
+                 Set MUJOCO_DIR to an extracted MuJoCo release, set MUJOCO_LIB_DIR and \
+                 MUJOCO_INCLUDE_DIR, or enable the `vendored-mujoco` feature"
+            ),
+        }
+    };
+
+    let header = include_dirs
+        .iter()
+        .map(|dir| dir.join("mujoco").join("mujoco.h"))
+        .find(|header| header.exists())
+        .unwrap_or_else(|| panic!("mujoco/mujoco.h not found in {include_dirs:?}"));
+    println!("cargo:rerun-if-changed={}", header.display());
+
+    let installed = read_define(&header, "mjVERSION_HEADER");
+    let bindings = read_define(std::path::Path::new("src/lib.rs"), "mjVERSION_HEADER");
+    if installed != bindings {
+        panic!(
+            "MuJoCo at {} is version {} but mujoco-sys was generated for {}",
+            header.display(),
+            format_version(installed),
+            format_version(bindings)
+        );
+    }
+
+    for dir in &lib_dirs {
+        println!("cargo:rustc-link-search=native={}", dir.display());
+    }
+    println!("cargo:rustc-link-lib=dylib=mujoco");
+    if let Some(include) = include_dirs.first() {
+        println!("cargo:include={}", include.display());
+    }
+}
+
+/// Read the numeric value of `#define NAME N` (C header) or `const NAME: u32 = N;` (bindings)
+#[cfg(not(feature = "vendored-mujoco"))]
+fn read_define(path: &std::path::Path, name: &str) -> u32 {
+    let source = std::fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {e}", path.display()));
+    source
+        .lines()
+        .find_map(|line| {
+            let rest = line.split_once(name)?.1;
+            let value = rest.rsplit_once('=').map_or(rest, |(_, value)| value);
+            value.trim().trim_end_matches(';').parse().ok()
+        })
+        .unwrap_or_else(|| panic!("{name} not found in {}", path.display()))
+}
+
+/// Format an mjVERSION_HEADER value (e.g. 3005000) as major.minor.patch
+#[cfg(not(feature = "vendored-mujoco"))]
+fn format_version(version: u32) -> String {
+    format!(
+        "{}.{}.{}",
+        version / 1_000_000,
+        version / 1_000 % 1_000,
+        version % 1_000
+    )
 }
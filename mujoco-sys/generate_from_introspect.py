@@ -37,6 +37,48 @@ RUST_ARRAY_TYPES = {
     "mjtSize": "i64",
 }
 
+# mjModel int arrays that hold enum values, returned as slices of the enum
+# Maps field name -> (enum type, has_setter, limit). Every array is checked by the generated
+# Model::check_enum_fields when a model is constructed (compiled, recompiled, loaded
+# from MJB or copied), so the 32-bit codes can then be reinterpreted in place.
+# limit is the exclusive upper bound of the codes a model may hold, for enums that also
+# have values only used elsewhere (e.g. the geom types 100+ used for rendering).
+# has_setter adds a mutable slice, only for fields MuJoCo reads without assuming a
+# layout: changing e.g. jnt_type would break the qpos addresses computed from it.
+# The *_sameframe arrays are stored as mjtByte and stay raw.
+MODEL_ENUM_FIELDS = {
+    "tree_sleep_policy": ("mjtSleepPolicy", False, None),
+    "jnt_type": ("mjtJoint", False, None),
+    "geom_type": ("mjtGeom", False, 'mjtGeom::mjNGEOMTYPES'),
+    "site_type": ("mjtGeom", False, 'mjtGeom::mjNGEOMTYPES'),
+    "cam_mode": ("mjtCamLight", False, None),
+    "cam_projection": ("mjtProjection", True, None),
+    "light_mode": ("mjtCamLight", False, None),
+    "light_type": ("mjtLightType", True, None),
+    "flex_selfcollide": ("mjtFlexSelf", False, None),
+    "tex_type": ("mjtTexture", False, None),
+    "tex_colorspace": ("mjtColorSpace", False, None),
+    "eq_type": ("mjtEq", False, None),
+    "eq_objtype": ("mjtObj", False, 'mjtObj::mjNOBJECT'),
+    "wrap_type": ("mjtWrap", False, None),
+    "actuator_trntype": ("mjtTrn", False, None),
+    "actuator_dyntype": ("mjtDyn", False, None),
+    "actuator_gaintype": ("mjtGain", True, None),
+    "actuator_biastype": ("mjtBias", True, None),
+    "sensor_type": ("mjtSensor", False, None),
+    "sensor_datatype": ("mjtDataType", False, None),
+    "sensor_needstage": ("mjtStage", False, None),
+    "sensor_objtype": ("mjtObj", False, 'mjtObj::mjNOBJECT'),
+    "sensor_reftype": ("mjtObj", False, 'mjtObj::mjNOBJECT'),
+}
+
+# Bit flag enums exposed as bitflags types with Model getters and setters
+# Maps enum name -> (value prefix, flags type, mjOption field, getter name)
+FLAG_ENUMS = {
+    "mjtDisableBit": ("mjDSBL_", "DisableFlags", "disableflags", "disable_flags"),
+    "mjtEnableBit": ("mjENBL_", "EnableFlags", "enableflags", "enable_flags"),
+}
+
 # Fields that should have nalgebra accessors generated
 # Maps field name -> (element_stride, nalgebra_type, count_field, has_setter)
 # element_stride: number of elements per item (3 for Vec3, 4 for Quat, 9 for Mat3)
@@ -64,12 +106,24 @@ NALGEBRA_DATA_FIELDS = {
     "mocap_quat": (4, "Quat", "nmocap", True),
 }
 
+# Typed id accepted by the nalgebra accessors of arrays with the given count
+COUNT_ID_TYPES = {
+    "nbody": "BodyId",
+    "ngeom": "GeomId",
+    "nsite": "SiteId",
+}
+
 SKIP_FUNCTIONS = {
     # Functions that are manually bound in Rust wrapper types
     # model.rs
     "mj_loadXML",
     "mj_deleteModel",
     "mj_copyModel",
+    "mj_saveModel",
+    "mj_loadModel",
+    "mj_loadModelBuffer",
+    "mj_sizeModel",
+    "mj_setKeyframe",
     # data.rs
     "mj_makeData",
     "mj_deleteData",
@@ -79,14 +133,34 @@ SKIP_FUNCTIONS = {
     "mj_parseXMLString",
     "mj_compile",
     "mj_deleteSpec",
-    # Complex derivative functions
+    "mj_makeSpec",
+    "mj_saveXMLString",
+    "mj_saveXML",
+    "mj_recompile",
+    # state.rs
+    "mj_stateSize",
+    "mj_getState",
+    "mj_setState",
+    "mj_extractState",
+    "mj_copyState",
+    # derivatives.rs
     "mjd_transitionFD",
     "mjd_inverseFD",
+    # mass_matrix.rs
+    "mj_fullM",
+    "mj_mulM",
+    "mj_mulM2",
+    "mj_solveM",
+    "mj_solveM2",
+    "mj_addM",
+    # constraint.rs
+    "mj_isSparse",
+    "mj_mulJacVec",
+    "mj_mulJacTVec",
     # Internal/low-level mj_ functions
     "mj_freeLastXML",
     "mj_defaultSolRefImp",
     "mj_resetCallbacks",
-    "mj_makeSpec",
     "mj_loadPluginLibrary",
     "mj_loadAllPluginLibraries",
     # VFS functions
@@ -97,14 +171,9 @@ SKIP_FUNCTIONS = {
     "mj_deleteVFS",
     # Spec functions
     "mj_copyBack",
-    "mj_recompile",
     "mj_copySpec",
     # XML/Save/Load with error buffers
     "mj_saveLastXML",
-    "mj_saveXMLString",
-    "mj_saveXML",
-    "mj_saveModel",
-    "mj_loadModel",
     "mj_printSchema",
     # Default/Init structs
     "mj_defaultLROpt",
@@ -118,28 +187,15 @@ SKIP_FUNCTIONS = {
     "mj_stackAllocInt",
     # Model query
     "mj_isPyramidal",
-    "mj_isSparse",
     "mj_isDual",
     "mj_getTotalmass",
-    "mj_sizeModel",
-    "mj_stateSize",
     # Simulation with extra params
     "mj_forwardSkip",
     "mj_inverseSkip",
     "mj_RungeKutta",
     "mj_resetDataDebug",
     # Solver/matrix functions
-    "mj_solveM",
-    "mj_solveM2",
     "mj_rne",
-    "mj_mulJacVec",
-    "mj_mulJacTVec",
-    "mj_mulM",
-    "mj_mulM2",
-    "mj_fullM",
-    "mj_getState",
-    "mj_setState",
-    "mj_addM",
     "mj_constraintUpdate",
     # Print functions
     "mj_printModel",
@@ -149,7 +205,6 @@ SKIP_FUNCTIONS = {
     # Misc
     "mj_setTotalmass",
     "mj_setConst",
-    "mj_setKeyframe",
     "mj_local2Global",
     "mj_applyFT",
     "mj_warning",
@@ -170,15 +225,11 @@ SKIP_FUNCTIONS = {
     "mj_clearCache",
     # Alternative model loading
     "mj_parse",
-    "mj_loadModelBuffer",
     # Length range
     "mj_setLengthRange",
     # Scene printing
     "mj_printScene",
     "mj_printFormattedScene",
-    # State management
-    "mj_extractState",
-    "mj_copyState",
     # History functions
     "mj_readCtrl",
     "mj_readSensor",
@@ -223,19 +274,29 @@ def generate_nalgebra_accessor(field_name, stride, nalgebra_type, count_field, h
     else:
         count_expr = f"self.{count_field}()"
 
+    # Element arrays are indexed by typed ids, other arrays (e.g. mocap) by position
+    id_type = COUNT_ID_TYPES.get(count_field)
+    if id_type:
+        index_param = f"id: crate::{id_type}"
+        index_check = f"""let idx = id.index();
+    assert!(idx < {count_expr}, "{field_name} index {{idx}} out of bounds");"""
+    else:
+        index_param = "idx: usize"
+        index_check = f"""assert!(idx < {count_expr}, "{field_name} index {{idx}} out of bounds");"""
+
     if nalgebra_type == "Vec3":
         getter = f"""
 /// Get {field_name} at index as Vec3
-pub fn get_{field_name}(&self, idx: usize) -> crate::Vec3 {{
-    debug_assert!(idx < {count_expr}, "{field_name} index out of bounds");
+pub fn get_{field_name}(&self, {index_param}) -> crate::Vec3 {{
+    {index_check}
     let data = self.{field_name}();
     let i = idx * {stride};
     crate::Vec3::new(data[i], data[i + 1], data[i + 2])
 }}"""
         setter = f"""
 /// Set {field_name} at index from Vec3
-pub fn set_{field_name}(&mut self, idx: usize, value: &crate::Vec3) {{
-    debug_assert!(idx < {count_expr}, "{field_name} index out of bounds");
+pub fn set_{field_name}(&mut self, {index_param}, value: &crate::Vec3) {{
+    {index_check}
     let data = self.{field_name}_mut();
     let i = idx * {stride};
     data[i] = value[0];
@@ -246,8 +307,8 @@ pub fn set_{field_name}(&mut self, idx: usize, value: &crate::Vec3) {{
     elif nalgebra_type == "Quat":
         getter = f"""
 /// Get {field_name} at index as UnitQuaternion
-pub fn get_{field_name}(&self, idx: usize) -> crate::Quat {{
-    debug_assert!(idx < {count_expr}, "{field_name} index out of bounds");
+pub fn get_{field_name}(&self, {index_param}) -> crate::Quat {{
+    {index_check}
     let data = self.{field_name}();
     let i = idx * {stride};
     // MuJoCo uses (w, x, y, z), nalgebra Quaternion::new takes (w, x, y, z)
@@ -257,8 +318,8 @@ pub fn get_{field_name}(&self, idx: usize) -> crate::Quat {{
 }}"""
         setter = f"""
 /// Set {field_name} at index from UnitQuaternion
-pub fn set_{field_name}(&mut self, idx: usize, value: &crate::Quat) {{
-    debug_assert!(idx < {count_expr}, "{field_name} index out of bounds");
+pub fn set_{field_name}(&mut self, {index_param}, value: &crate::Quat) {{
+    {index_check}
     let data = self.{field_name}_mut();
     let i = idx * {stride};
     data[i] = value.w;
@@ -270,8 +331,8 @@ pub fn set_{field_name}(&mut self, idx: usize, value: &crate::Quat) {{
     elif nalgebra_type == "Mat3":
         getter = f"""
 /// Get {field_name} at index as Mat3
-pub fn get_{field_name}(&self, idx: usize) -> crate::Mat3 {{
-    debug_assert!(idx < {count_expr}, "{field_name} index out of bounds");
+pub fn get_{field_name}(&self, {index_param}) -> crate::Mat3 {{
+    {index_check}
     let data = self.{field_name}();
     let i = idx * {stride};
     // MuJoCo stores row-major, nalgebra is column-major
@@ -279,8 +340,8 @@ pub fn get_{field_name}(&self, idx: usize) -> crate::Mat3 {{
 }}"""
         setter = f"""
 /// Set {field_name} at index from Mat3
-pub fn set_{field_name}(&mut self, idx: usize, value: &crate::Mat3) {{
-    debug_assert!(idx < {count_expr}, "{field_name} index out of bounds");
+pub fn set_{field_name}(&mut self, {index_param}, value: &crate::Mat3) {{
+    {index_check}
     let data = self.{field_name}_mut();
     let i = idx * {stride};
     // Convert from column-major nalgebra to row-major MuJoCo
@@ -297,6 +358,53 @@ pub fn set_{field_name}(&mut self, idx: usize, value: &crate::Mat3) {{
     return (getter, setter)
 
 
+def generate_flags_rs():
+    """Generate flags.rs with bitflags types for the mjOption flag fields."""
+    blocks = []
+    accessors = []
+    for enum_name, (prefix, flags_type, option_field, getter) in FLAG_ENUMS.items():
+        constants = []
+        for key in enums.ENUMS[enum_name].values:
+            if key.startswith("mjN"):
+                continue
+            name = key.removeprefix(prefix)
+            constants.append(f"        const {name} = {enum_name}::{name} as u32;")
+        constants = "\n".join(constants)
+        blocks.append(f"""
+bitflags::bitflags! {{
+    /// Flags of `mjOption::{option_field}` ({enum_name})
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct {flags_type}: u32 {{
+{constants}
+    }}
+}}
+""")
+        accessors.append(f"""
+    /// Flags set in `mjOption::{option_field}`, unknown bits are dropped
+    pub fn {getter}(&self) -> {flags_type} {{
+        {flags_type}::from_bits_truncate(self.raw().opt.{option_field} as u32)
+    }}
+
+    /// Replace `mjOption::{option_field}`
+    pub fn set_{getter}(&mut self, flags: {flags_type}) {{
+        self.raw_mut().opt.{option_field} = flags.bits() as i32;
+    }}""")
+    content = f"""//! Bit flags of the physics options
+//! Generated by generate_from_introspect.py - DO NOT EDIT MANUALLY
+
+use crate::Model;
+use mujoco_sys::{{{', '.join(sorted(FLAG_ENUMS))}}};
+{"".join(blocks)}
+impl Model {{{"\n".join(accessors)}
+}}
+"""
+    output_path = FILE_DIR / ".." / "mujoco" / "src" / "flags.rs"
+    with open(output_path, "w") as f:
+        f.write(content)
+    print("Generated flags.rs")
+
+
 def generate_math_rs():
     """Generate math.rs with nalgebra type aliases."""
     content = '''//! Math type aliases using nalgebra
@@ -380,6 +488,7 @@ model_struct = structs.STRUCTS["mjModel"]
 model_accessors = []
 model_includes = set(["mjtNum"])
 model_fields = set()
+model_enum_checks = []
 
 for field in model_struct.fields:
     model_fields.add(field.name)
@@ -399,6 +508,14 @@ for field in model_struct.fields:
             if field.array_extent is None and field.type.inner_type.name == "void":
                 print(f"mjModel: Skipping void pointer field: {field.name}")
                 continue
+            pointer = f"self.raw().{field.name}"
+            enum_type, enum_setter, enum_limit = MODEL_ENUM_FIELDS.get(
+                field.name, (None, False, None)
+            )
+            if enum_type:
+                rust_type = enum_type
+                model_includes.add(rust_type)
+                pointer = f"{pointer} as *const {rust_type}"
             array_extents = []
             for extent in field.array_extent:
                 if isinstance(extent, int):
@@ -423,10 +540,25 @@ for field in model_struct.fields:
 /// {field.doc}
 pub fn {field.name}(&self) -> &[{rust_type}] {{
     unsafe {{
-        std::slice::from_raw_parts(self.raw().{field.name}, {array_extent})
+        std::slice::from_raw_parts({pointer}, {array_extent})
     }}
 }}"""
             )
+            if enum_type:
+                limit = f"Some({enum_limit} as i32)" if enum_limit else "None"
+                model_enum_checks.append(
+                    f"crate::model::check_enum_codes::<{enum_type}>(self.raw().{field.name}, {array_extent}, \"{field.name}\", {limit})?;"
+                )
+            if enum_setter:
+                model_accessors.append(
+                    f"""
+/// {field.doc}
+pub fn {field.name}_mut(&mut self) -> &mut [{rust_type}] {{
+    unsafe {{
+        std::slice::from_raw_parts_mut(self.raw_mut().{field.name} as *mut {rust_type}, {array_extent})
+    }}
+}}"""
+                )
 
         case structs.ArrayType:
             accessor, includes = generate_array_accessor(field)
@@ -435,6 +567,15 @@ pub fn {field.name}(&self) -> &[{rust_type}] {{
         case _:
             raise NotImplementedError(f"Unsupported field type: {field}")
 
+model_accessors.append(
+    f"""
+/// Check that the enum arrays only hold valid codes, before they are handed out as enums
+pub(crate) fn check_enum_fields(&self) -> crate::Result<()> {{
+    {"\n    ".join(model_enum_checks)}
+    Ok(())
+}}"""
+)
+
 model_includes.discard("void")
 model_header = f"""//! Auto-generated Model accessor functions
 //! Generated by generate_from_introspect.py - DO NOT EDIT MANUALLY
@@ -560,8 +701,10 @@ pub fn version_string() -> &'static str {{
 }}""",
     "mj_name2id": lambda f: f"""
 /// {f.doc}
+/// Covers every object type, including those without a typed id (meshes, materials, ...);
+/// prefer the typed lookups such as [`crate::BodyId::from_name`] where one exists.
 pub fn name2id(model: &crate::Model, obj_type: mujoco_sys::mjtObj, name: &str) -> Option<i32> {{
-    let name_cstr = std::ffi::CString::new(name).unwrap();
+    let name_cstr = std::ffi::CString::new(name).ok()?;
     let id = unsafe {{ mujoco_sys::mj_name2id(model.as_ptr(), obj_type as i32, name_cstr.as_ptr()) }};
     if id >= 0 {{ Some(id) }} else {{ None }}
 }}""",
@@ -579,8 +722,9 @@ pub fn id2name(model: &crate::Model, obj_type: mujoco_sys::mjtObj, id: i32) -> O
 }}""",
     "mj_resetDataKeyframe": lambda f: f"""
 /// {f.doc}
-pub fn reset_data_keyframe(data: &mut crate::Data, key: i32) {{
-    unsafe {{ mujoco_sys::mj_resetDataKeyframe(data.model.as_ptr(), data.as_mut_ptr(), key) }}
+pub fn reset_data_keyframe(data: &mut crate::Data, key: impl crate::IntoId<crate::KeyframeId>) -> crate::Result<()> {{
+    let key = key.into_id(data.model())?;
+    crate::handlers::guard(|| unsafe {{ mujoco_sys::mj_resetDataKeyframe(data.model.as_ptr(), data.as_mut_ptr(), key.index() as i32) }})
 }}""",
     "mj_differentiatePos": lambda f: f"""
 /// {f.doc}
@@ -599,173 +743,154 @@ pub fn normalize_quat(model: &crate::Model, qpos: &mut [f64]) {{
 }}""",
     "mj_objectVelocity": lambda f: f"""
 /// {f.doc}
-pub fn object_velocity(data: &crate::Data, objtype: mujoco_sys::mjtObj, objid: i32, flg_local: bool) -> crate::Vec6 {{
+pub fn object_velocity(data: &crate::Data, object: impl Into<crate::Object>, flg_local: bool) -> crate::Result<crate::Vec6> {{
+    let (objtype, objid) = object.into().checked(data.model())?;
     let mut res = crate::Vec6::zeros();
-    unsafe {{ mujoco_sys::mj_objectVelocity(data.model.as_ptr(), data.as_ptr(), objtype as i32, objid, res.as_mut_ptr(), flg_local as i32) }}
-    res
+    crate::handlers::guard(|| unsafe {{ mujoco_sys::mj_objectVelocity(data.model.as_ptr(), data.as_ptr(), objtype as i32, objid, res.as_mut_ptr(), flg_local as i32) }})?;
+    Ok(res)
 }}""",
     "mj_objectAcceleration": lambda f: f"""
 /// {f.doc}
-pub fn object_acceleration(data: &crate::Data, objtype: mujoco_sys::mjtObj, objid: i32, flg_local: bool) -> crate::Vec6 {{
+pub fn object_acceleration(data: &crate::Data, object: impl Into<crate::Object>, flg_local: bool) -> crate::Result<crate::Vec6> {{
+    let (objtype, objid) = object.into().checked(data.model())?;
     let mut res = crate::Vec6::zeros();
-    unsafe {{ mujoco_sys::mj_objectAcceleration(data.model.as_ptr(), data.as_ptr(), objtype as i32, objid, res.as_mut_ptr(), flg_local as i32) }}
-    res
+    crate::handlers::guard(|| unsafe {{ mujoco_sys::mj_objectAcceleration(data.model.as_ptr(), data.as_ptr(), objtype as i32, objid, res.as_mut_ptr(), flg_local as i32) }})?;
+    Ok(res)
 }}""",
     "mj_contactForce": lambda f: f"""
 /// {f.doc}
-pub fn contact_force(data: &crate::Data, id: i32) -> crate::Vec6 {{
+/// Returns [`crate::Error::InvalidBuffer`] if `id` is not smaller than `data.ncon()`.
+pub fn contact_force(data: &crate::Data, id: usize) -> crate::Result<crate::Vec6> {{
+    if id >= data.ncon() {{
+        return Err(crate::Error::InvalidBuffer(format!("contact index {{id}} out of bounds (ncon = {{}})", data.ncon())));
+    }}
     let mut res = crate::Vec6::zeros();
-    unsafe {{ mujoco_sys::mj_contactForce(data.model.as_ptr(), data.as_ptr(), id, res.as_mut_ptr()) }}
-    res
+    crate::handlers::guard(|| unsafe {{ mujoco_sys::mj_contactForce(data.model.as_ptr(), data.as_ptr(), id as i32, res.as_mut_ptr()) }})?;
+    Ok(res)
 }}""",
     "mj_geomDistance": lambda f: f"""
 /// {f.doc}
-pub fn geom_distance(data: &crate::Data, geom1: i32, geom2: i32, distmax: f64) -> (f64, crate::Vec6) {{
+pub fn geom_distance(data: &crate::Data, geom1: impl crate::IntoId<crate::GeomId>, geom2: impl crate::IntoId<crate::GeomId>, distmax: f64) -> crate::Result<(f64, crate::Vec6)> {{
+    let geom1 = geom1.into_id(data.model())?;
+    let geom2 = geom2.into_id(data.model())?;
     let mut fromto = crate::Vec6::zeros();
-    let dist = unsafe {{ mujoco_sys::mj_geomDistance(data.model.as_ptr(), data.as_ptr(), geom1, geom2, distmax, fromto.as_mut_ptr()) }};
-    (dist, fromto)
+    let dist = crate::handlers::guard(|| unsafe {{ mujoco_sys::mj_geomDistance(data.model.as_ptr(), data.as_ptr(), geom1.index() as i32, geom2.index() as i32, distmax, fromto.as_mut_ptr()) }})?;
+    Ok((dist, fromto))
 }}""",
     "mj_jac": lambda f: f"""
 /// {f.doc}
 /// Returns a 6×nv Jacobian matrix (top 3 rows: position, bottom 3 rows: rotation).
-pub fn jac(data: &crate::Data, point: &crate::Vec3, body: i32) -> crate::Jacobian6xN {{
-    debug_assert!(
-        (body as usize) < data.model.nbody(),
-        "body index {{}} out of bounds (nbody = {{}})", body, data.model.nbody()
-    );
+pub fn jac(data: &crate::Data, point: &crate::Vec3, body: impl crate::IntoId<crate::BodyId>) -> crate::Result<crate::Jacobian6xN> {{
+    let body = body.into_id(data.model())?;
     let nv = data.model.nv();
     let mut jac_t = crate::JacobianNx6::zeros(nv);
-    unsafe {{
+    crate::handlers::guard(|| unsafe {{
         let ptr = jac_t.as_mut_ptr();
-        mujoco_sys::mj_jac(data.model.as_ptr(), data.as_ptr(), ptr, ptr.add(3 * nv), point.as_ptr(), body);
-    }}
-    jac_t.transpose()
+        mujoco_sys::mj_jac(data.model.as_ptr(), data.as_ptr(), ptr, ptr.add(3 * nv), point.as_ptr(), body.index() as i32);
+    }})?;
+    Ok(jac_t.transpose())
 }}""",
     "mj_jacBody": lambda f: f"""
 /// {f.doc}
 /// Returns a 6×nv Jacobian matrix (top 3 rows: position, bottom 3 rows: rotation).
-pub fn jac_body(data: &crate::Data, body: i32) -> crate::Jacobian6xN {{
-    debug_assert!(
-        (body as usize) < data.model.nbody(),
-        "body index {{}} out of bounds (nbody = {{}})", body, data.model.nbody()
-    );
+pub fn jac_body(data: &crate::Data, body: impl crate::IntoId<crate::BodyId>) -> crate::Result<crate::Jacobian6xN> {{
+    let body = body.into_id(data.model())?;
     let nv = data.model.nv();
     let mut jac_t = crate::JacobianNx6::zeros(nv);
-    unsafe {{
+    crate::handlers::guard(|| unsafe {{
         let ptr = jac_t.as_mut_ptr();
-        mujoco_sys::mj_jacBody(data.model.as_ptr(), data.as_ptr(), ptr, ptr.add(3 * nv), body);
-    }}
-    jac_t.transpose()
+        mujoco_sys::mj_jacBody(data.model.as_ptr(), data.as_ptr(), ptr, ptr.add(3 * nv), body.index() as i32);
+    }})?;
+    Ok(jac_t.transpose())
 }}""",
     "mj_jacBodyCom": lambda f: f"""
 /// {f.doc}
 /// Returns a 6×nv Jacobian matrix (top 3 rows: position, bottom 3 rows: rotation).
-pub fn jac_body_com(data: &crate::Data, body: i32) -> crate::Jacobian6xN {{
-    debug_assert!(
-        (body as usize) < data.model.nbody(),
-        "body index {{}} out of bounds (nbody = {{}})", body, data.model.nbody()
-    );
+pub fn jac_body_com(data: &crate::Data, body: impl crate::IntoId<crate::BodyId>) -> crate::Result<crate::Jacobian6xN> {{
+    let body = body.into_id(data.model())?;
     let nv = data.model.nv();
     let mut jac_t = crate::JacobianNx6::zeros(nv);
-    unsafe {{
+    crate::handlers::guard(|| unsafe {{
         let ptr = jac_t.as_mut_ptr();
-        mujoco_sys::mj_jacBodyCom(data.model.as_ptr(), data.as_ptr(), ptr, ptr.add(3 * nv), body);
-    }}
-    jac_t.transpose()
+        mujoco_sys::mj_jacBodyCom(data.model.as_ptr(), data.as_ptr(), ptr, ptr.add(3 * nv), body.index() as i32);
+    }})?;
+    Ok(jac_t.transpose())
 }}""",
     "mj_jacSubtreeCom": lambda f: f"""
 /// {f.doc}
 /// Returns a 3×nv position Jacobian matrix.
-pub fn jac_subtree_com(data: &mut crate::Data, body: i32) -> crate::Jacobian3xN {{
-    debug_assert!(
-        (body as usize) < data.model.nbody(),
-        "body index {{}} out of bounds (nbody = {{}})", body, data.model.nbody()
-    );
+pub fn jac_subtree_com(data: &mut crate::Data, body: impl crate::IntoId<crate::BodyId>) -> crate::Result<crate::Jacobian3xN> {{
+    let body = body.into_id(data.model())?;
     let nv = data.model.nv();
     let mut jac_t = crate::JacobianNx3::zeros(nv);
-    unsafe {{
-        mujoco_sys::mj_jacSubtreeCom(data.model.as_ptr(), data.as_mut_ptr(), jac_t.as_mut_ptr(), body);
-    }}
-    jac_t.transpose()
+    crate::handlers::guard(|| unsafe {{
+        mujoco_sys::mj_jacSubtreeCom(data.model.as_ptr(), data.as_mut_ptr(), jac_t.as_mut_ptr(), body.index() as i32);
+    }})?;
+    Ok(jac_t.transpose())
 }}""",
     "mj_jacGeom": lambda f: f"""
 /// {f.doc}
 /// Returns a 6×nv Jacobian matrix (top 3 rows: position, bottom 3 rows: rotation).
-pub fn jac_geom(data: &crate::Data, geom: i32) -> crate::Jacobian6xN {{
-    debug_assert!(
-        (geom as usize) < data.model.ngeom(),
-        "geom index {{}} out of bounds (ngeom = {{}})", geom, data.model.ngeom()
-    );
+pub fn jac_geom(data: &crate::Data, geom: impl crate::IntoId<crate::GeomId>) -> crate::Result<crate::Jacobian6xN> {{
+    let geom = geom.into_id(data.model())?;
     let nv = data.model.nv();
     let mut jac_t = crate::JacobianNx6::zeros(nv);
-    unsafe {{
+    crate::handlers::guard(|| unsafe {{
         let ptr = jac_t.as_mut_ptr();
-        mujoco_sys::mj_jacGeom(data.model.as_ptr(), data.as_ptr(), ptr, ptr.add(3 * nv), geom);
-    }}
-    jac_t.transpose()
+        mujoco_sys::mj_jacGeom(data.model.as_ptr(), data.as_ptr(), ptr, ptr.add(3 * nv), geom.index() as i32);
+    }})?;
+    Ok(jac_t.transpose())
 }}""",
     "mj_jacSite": lambda f: f"""
 /// {f.doc}
 /// Returns a 6×nv Jacobian matrix (top 3 rows: position, bottom 3 rows: rotation).
-pub fn jac_site(data: &crate::Data, site: i32) -> crate::Jacobian6xN {{
-    debug_assert!(
-        (site as usize) < data.model.nsite(),
-        "site index {{}} out of bounds (nsite = {{}})", site, data.model.nsite()
-    );
+pub fn jac_site(data: &crate::Data, site: impl crate::IntoId<crate::SiteId>) -> crate::Result<crate::Jacobian6xN> {{
+    let site = site.into_id(data.model())?;
     let nv = data.model.nv();
     let mut jac_t = crate::JacobianNx6::zeros(nv);
-    unsafe {{
+    crate::handlers::guard(|| unsafe {{
         let ptr = jac_t.as_mut_ptr();
-        mujoco_sys::mj_jacSite(data.model.as_ptr(), data.as_ptr(), ptr, ptr.add(3 * nv), site);
-    }}
-    jac_t.transpose()
+        mujoco_sys::mj_jacSite(data.model.as_ptr(), data.as_ptr(), ptr, ptr.add(3 * nv), site.index() as i32);
+    }})?;
+    Ok(jac_t.transpose())
 }}""",
     "mj_jacPointAxis": lambda f: f"""
 /// {f.doc}
 /// Returns a 6×nv Jacobian matrix (top 3 rows: point translation, bottom 3 rows: axis rotation).
-pub fn jac_point_axis(data: &mut crate::Data, point: &crate::Vec3, axis: &crate::Vec3, body: i32) -> crate::Jacobian6xN {{
-    debug_assert!(
-        (body as usize) < data.model.nbody(),
-        "body index {{}} out of bounds (nbody = {{}})", body, data.model.nbody()
-    );
+pub fn jac_point_axis(data: &mut crate::Data, point: &crate::Vec3, axis: &crate::Vec3, body: impl crate::IntoId<crate::BodyId>) -> crate::Result<crate::Jacobian6xN> {{
+    let body = body.into_id(data.model())?;
     let nv = data.model.nv();
     let mut jac_t = crate::JacobianNx6::zeros(nv);
-    unsafe {{
+    crate::handlers::guard(|| unsafe {{
         let ptr = jac_t.as_mut_ptr();
-        mujoco_sys::mj_jacPointAxis(data.model.as_ptr(), data.as_mut_ptr(), ptr, ptr.add(3 * nv), point.as_ptr(), axis.as_ptr(), body);
-    }}
-    jac_t.transpose()
+        mujoco_sys::mj_jacPointAxis(data.model.as_ptr(), data.as_mut_ptr(), ptr, ptr.add(3 * nv), point.as_ptr(), axis.as_ptr(), body.index() as i32);
+    }})?;
+    Ok(jac_t.transpose())
 }}""",
     "mj_jacDot": lambda f: f"""
 /// {f.doc}
 /// Returns a 6×nv Jacobian time derivative matrix (top 3 rows: position, bottom 3 rows: rotation).
-pub fn jac_dot(data: &crate::Data, point: &crate::Vec3, body: i32) -> crate::Jacobian6xN {{
-    debug_assert!(
-        (body as usize) < data.model.nbody(),
-        "body index {{}} out of bounds (nbody = {{}})", body, data.model.nbody()
-    );
+pub fn jac_dot(data: &crate::Data, point: &crate::Vec3, body: impl crate::IntoId<crate::BodyId>) -> crate::Result<crate::Jacobian6xN> {{
+    let body = body.into_id(data.model())?;
     let nv = data.model.nv();
     let mut jac_t = crate::JacobianNx6::zeros(nv);
-    unsafe {{
+    crate::handlers::guard(|| unsafe {{
         let ptr = jac_t.as_mut_ptr();
-        mujoco_sys::mj_jacDot(data.model.as_ptr(), data.as_ptr(), ptr, ptr.add(3 * nv), point.as_ptr(), body);
-    }}
-    jac_t.transpose()
+        mujoco_sys::mj_jacDot(data.model.as_ptr(), data.as_ptr(), ptr, ptr.add(3 * nv), point.as_ptr(), body.index() as i32);
+    }})?;
+    Ok(jac_t.transpose())
 }}""",
     "mj_angmomMat": lambda f: f"""
 /// {f.doc}
 /// Returns a 3×nv angular momentum matrix.
-pub fn angmom_mat(data: &mut crate::Data, body: i32) -> crate::Jacobian3xN {{
-    debug_assert!(
-        (body as usize) < data.model.nbody(),
-        "body index {{}} out of bounds (nbody = {{}})", body, data.model.nbody()
-    );
+pub fn angmom_mat(data: &mut crate::Data, body: impl crate::IntoId<crate::BodyId>) -> crate::Result<crate::Jacobian3xN> {{
+    let body = body.into_id(data.model())?;
     let nv = data.model.nv();
     let mut mat_t = crate::JacobianNx3::zeros(nv);
-    unsafe {{
-        mujoco_sys::mj_angmomMat(data.model.as_ptr(), data.as_mut_ptr(), mat_t.as_mut_ptr(), body);
-    }}
-    mat_t.transpose()
+    crate::handlers::guard(|| unsafe {{
+        mujoco_sys::mj_angmomMat(data.model.as_ptr(), data.as_mut_ptr(), mat_t.as_mut_ptr(), body.index() as i32);
+    }})?;
+    Ok(mat_t.transpose())
 }}""",
 }
 
@@ -808,10 +933,10 @@ for function_name, function in functions.FUNCTIONS.items():
     data_functions.append(
         f"""
 /// {function.doc}
-pub fn {function_name}(data: &mut Data) {{
-        unsafe {{
+pub fn {function_name}(data: &mut Data) -> crate::Result<()> {{
+        crate::handlers::guard(|| unsafe {{
             mujoco_sys::{function.name}(data.model.as_ptr(), data.as_mut_ptr());
-        }}
+        }})
     }}"""
     )
 data_functions_header = f"""//! Auto-generated Data functions
@@ -854,6 +979,61 @@ impl From<usize> for {enum_name} {{
     return "\n".join(lines)
 
 
+# Enums stored as raw ints in mjModel that get a checked TryFrom<i32> conversion
+# Maps enum name -> value prefix stripped by update-ffi.sh ("" if it is kept)
+TRY_FROM_ENUMS = {
+    "mjtSleepPolicy": "",
+    "mjtJoint": "mjJNT_",
+    "mjtGeom": "mjGEOM_",
+    "mjtCamLight": "mjCAMLIGHT_",
+    "mjtProjection": "",
+    "mjtLightType": "mjLIGHT_",
+    "mjtFlexSelf": "mjFLEXSELF_",
+    "mjtTexture": "mjTEXTURE_",
+    "mjtColorSpace": "mjCOLORSPACE_",
+    "mjtEq": "mjEQ_",
+    "mjtObj": "mjOBJ_",
+    "mjtWrap": "mjWRAP_",
+    "mjtTrn": "mjTRN_",
+    "mjtDyn": "mjDYN_",
+    "mjtGain": "mjGAIN_",
+    "mjtBias": "mjBIAS_",
+    "mjtSensor": "mjSENS_",
+    "mjtDataType": "mjDATATYPE_",
+    "mjtStage": "mjSTAGE_",
+}
+
+
+def generate_try_from_trait(enum_name, prefix):
+    enum = enums.ENUMS[enum_name]
+    lines = [
+        f"""
+impl TryFrom<i32> for {enum_name} {{
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {{
+        match value {{
+"""
+    ]
+    for key, value in enum.values.items():
+        if key.startswith("mjN"):
+            # Count sentinels are not values of the enum
+            continue
+        variant_name = key.removeprefix(prefix)
+        if not variant_name[0].isalpha():
+            # update-ffi.sh keeps the prefix when the rest is not an identifier
+            variant_name = key
+        lines.append(f"            {value} => Ok({enum_name}::{variant_name}),")
+    lines.append(
+        """
+            _ => Err(value),
+        }
+    }
+}"""
+    )
+    return "\n".join(lines)
+
+
 LIB_RS_MARKER = "// === GENERATED BY generate_from_introspect.py - DO NOT EDIT BELOW THIS LINE ==="
 
 lib_rs_path = FILE_DIR / "src" / "lib.rs"
@@ -865,6 +1045,11 @@ with lib_rs_path.open("w") as f:
     f.write(LIB_RS_MARKER + "\n")
     f.write(generate_from_trait("mjtGeom", "mjGEOM_"))
     f.write(generate_from_trait("mjtJoint", "mjJNT_"))
+    for enum_name, prefix in TRY_FROM_ENUMS.items():
+        f.write(generate_try_from_trait(enum_name, prefix))
 
 # Generate math.rs with nalgebra type aliases
 generate_math_rs()
+
+# Generate flags.rs with bitflags types
+generate_flags_rs()
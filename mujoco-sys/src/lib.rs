@@ -3168,94 +3168,94 @@ pub struct mjuiDef_ {
     pub otherint: ::std::os::raw::c_int,
 }
 pub type mjuiDef = mjuiDef_;
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub static mut mju_user_error:
         ::std::option::Option<unsafe extern "C" fn(arg1: *const ::std::os::raw::c_char)>;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub static mut mju_user_warning:
         ::std::option::Option<unsafe extern "C" fn(arg1: *const ::std::os::raw::c_char)>;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub static mut mju_user_malloc:
         ::std::option::Option<unsafe extern "C" fn(arg1: usize) -> *mut ::std::os::raw::c_void>;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub static mut mju_user_free:
         ::std::option::Option<unsafe extern "C" fn(arg1: *mut ::std::os::raw::c_void)>;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub static mut mjcb_passive: mjfGeneric;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub static mut mjcb_control: mjfGeneric;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub static mut mjcb_contactfilter: mjfConFilt;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub static mut mjcb_sensor: mjfSensor;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub static mut mjcb_time: mjfTime;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub static mut mjcb_act_dyn: mjfAct;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub static mut mjcb_act_gain: mjfAct;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub static mut mjcb_act_bias: mjfAct;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub static mut mjCOLLISIONFUNC: [[mjfCollision; 9usize]; 9usize];
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub static mut mjDISABLESTRING: [*const ::std::os::raw::c_char; 19usize];
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub static mut mjENABLESTRING: [*const ::std::os::raw::c_char; 6usize];
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub static mut mjTIMERSTRING: [*const ::std::os::raw::c_char; 15usize];
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub static mut mjLABELSTRING: [*const ::std::os::raw::c_char; 17usize];
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub static mut mjFRAMESTRING: [*const ::std::os::raw::c_char; 8usize];
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub static mut mjVISSTRING: [[*const ::std::os::raw::c_char; 3usize]; 31usize];
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub static mut mjRNDSTRING: [[*const ::std::os::raw::c_char; 3usize]; 11usize];
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_defaultVFS(vfs: *mut mjVFS);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_mountVFS(
         vfs: *mut mjVFS,
         filepath: *const ::std::os::raw::c_char,
         provider: *const mjpResourceProvider,
     ) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_unmountVFS(
         vfs: *mut mjVFS,
         filename: *const ::std::os::raw::c_char,
     ) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_addFileVFS(
         vfs: *mut mjVFS,
         directory: *const ::std::os::raw::c_char,
         filename: *const ::std::os::raw::c_char,
     ) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_addBufferVFS(
         vfs: *mut mjVFS,
         name: *const ::std::os::raw::c_char,
@@ -3263,31 +3263,31 @@ unsafe extern "C" {
         nbuffer: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_deleteFileVFS(
         vfs: *mut mjVFS,
         filename: *const ::std::os::raw::c_char,
     ) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_deleteVFS(vfs: *mut mjVFS);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_getCacheSize(cache: *const mjCache) -> usize;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_getCacheCapacity(cache: *const mjCache) -> usize;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_setCacheCapacity(cache: *mut mjCache, size: usize) -> usize;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_getCache() -> *mut mjCache;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_clearCache(cache: *mut mjCache);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_loadXML(
         filename: *const ::std::os::raw::c_char,
         vfs: *const mjVFS,
@@ -3295,7 +3295,7 @@ unsafe extern "C" {
         error_sz: ::std::os::raw::c_int,
     ) -> *mut mjModel;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_parseXML(
         filename: *const ::std::os::raw::c_char,
         vfs: *const mjVFS,
@@ -3303,7 +3303,7 @@ unsafe extern "C" {
         error_sz: ::std::os::raw::c_int,
     ) -> *mut mjSpec;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_parseXMLString(
         xml: *const ::std::os::raw::c_char,
         vfs: *const mjVFS,
@@ -3311,7 +3311,7 @@ unsafe extern "C" {
         error_sz: ::std::os::raw::c_int,
     ) -> *mut mjSpec;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_parse(
         filename: *const ::std::os::raw::c_char,
         content_type: *const ::std::os::raw::c_char,
@@ -3320,13 +3320,13 @@ unsafe extern "C" {
         error_sz: ::std::os::raw::c_int,
     ) -> *mut mjSpec;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_compile(s: *mut mjSpec, vfs: *const mjVFS) -> *mut mjModel;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_copyBack(s: *mut mjSpec, m: *const mjModel) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_recompile(
         s: *mut mjSpec,
         vfs: *const mjVFS,
@@ -3334,7 +3334,7 @@ unsafe extern "C" {
         d: *mut mjData,
     ) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_saveLastXML(
         filename: *const ::std::os::raw::c_char,
         m: *const mjModel,
@@ -3342,10 +3342,10 @@ unsafe extern "C" {
         error_sz: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_freeLastXML();
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_saveXMLString(
         s: *const mjSpec,
         xml: *mut ::std::os::raw::c_char,
@@ -3354,7 +3354,7 @@ unsafe extern "C" {
         error_sz: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_saveXML(
         s: *const mjSpec,
         filename: *const ::std::os::raw::c_char,
@@ -3362,28 +3362,28 @@ unsafe extern "C" {
         error_sz: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_getXMLDependencies(
         filename: *const ::std::os::raw::c_char,
         dependencies: *mut mjStringVec,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_step(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_step1(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_step2(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_forward(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_inverse(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_forwardSkip(
         m: *const mjModel,
         d: *mut mjData,
@@ -3391,7 +3391,7 @@ unsafe extern "C" {
         skipsensor: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_inverseSkip(
         m: *const mjModel,
         d: *mut mjData,
@@ -3399,22 +3399,22 @@ unsafe extern "C" {
         skipsensor: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_defaultLROpt(opt: *mut mjLROpt);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_defaultSolRefImp(solref: *mut mjtNum, solimp: *mut mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_defaultOption(opt: *mut mjOption);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_defaultVisual(vis: *mut mjVisual);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_copyModel(dest: *mut mjModel, src: *const mjModel) -> *mut mjModel;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_saveModel(
         m: *const mjModel,
         filename: *const ::std::os::raw::c_char,
@@ -3422,73 +3422,73 @@ unsafe extern "C" {
         buffer_sz: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_loadModel(filename: *const ::std::os::raw::c_char, vfs: *const mjVFS)
     -> *mut mjModel;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_loadModelBuffer(
         buffer: *const ::std::os::raw::c_void,
         buffer_sz: ::std::os::raw::c_int,
     ) -> *mut mjModel;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_deleteModel(m: *mut mjModel);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_sizeModel(m: *const mjModel) -> mjtSize;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_makeData(m: *const mjModel) -> *mut mjData;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_copyData(dest: *mut mjData, m: *const mjModel, src: *const mjData) -> *mut mjData;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_copyData(dest: *mut mjData, m: *const mjModel, src: *const mjData) -> *mut mjData;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_resetData(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_resetDataDebug(
         m: *const mjModel,
         d: *mut mjData,
         debug_value: ::std::os::raw::c_uchar,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_resetDataKeyframe(m: *const mjModel, d: *mut mjData, key: ::std::os::raw::c_int);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_markStack(d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_freeStack(d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_stackAllocByte(
         d: *mut mjData,
         bytes: usize,
         alignment: usize,
     ) -> *mut ::std::os::raw::c_void;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_stackAllocNum(d: *mut mjData, size: usize) -> *mut mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_stackAllocInt(d: *mut mjData, size: usize) -> *mut ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_deleteData(d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_resetCallbacks();
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_setConst(m: *mut mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_setLengthRange(
         m: *mut mjModel,
         d: *mut mjData,
@@ -3498,38 +3498,38 @@ unsafe extern "C" {
         error_sz: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_makeSpec() -> *mut mjSpec;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_copySpec(s: *const mjSpec) -> *mut mjSpec;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_deleteSpec(s: *mut mjSpec);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_activatePlugin(
         s: *mut mjSpec,
         name: *const ::std::os::raw::c_char,
     ) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_setDeepCopy(
         s: *mut mjSpec,
         deepcopy: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_printFormattedModel(
         m: *const mjModel,
         filename: *const ::std::os::raw::c_char,
         float_format: *const ::std::os::raw::c_char,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_printModel(m: *const mjModel, filename: *const ::std::os::raw::c_char);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_printFormattedData(
         m: *const mjModel,
         d: *const mjData,
@@ -3537,17 +3537,17 @@ unsafe extern "C" {
         float_format: *const ::std::os::raw::c_char,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_printData(
         m: *const mjModel,
         d: *const mjData,
         filename: *const ::std::os::raw::c_char,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_printMat(mat: *const mjtNum, nr: ::std::os::raw::c_int, nc: ::std::os::raw::c_int);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_printMatSparse(
         mat: *const mjtNum,
         nr: ::std::os::raw::c_int,
@@ -3556,7 +3556,7 @@ unsafe extern "C" {
         colind: *const ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_printSchema(
         filename: *const ::std::os::raw::c_char,
         buffer: *mut ::std::os::raw::c_char,
@@ -3565,107 +3565,107 @@ unsafe extern "C" {
         flg_pad: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_printScene(s: *const mjvScene, filename: *const ::std::os::raw::c_char);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_printFormattedScene(
         s: *const mjvScene,
         filename: *const ::std::os::raw::c_char,
         float_format: *const ::std::os::raw::c_char,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_fwdKinematics(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_fwdPosition(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_fwdVelocity(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_fwdActuation(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_fwdAcceleration(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_fwdConstraint(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_Euler(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_RungeKutta(m: *const mjModel, d: *mut mjData, N: ::std::os::raw::c_int);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_implicit(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_invPosition(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_invVelocity(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_invConstraint(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_compareFwdInv(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_sensorPos(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_sensorVel(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_sensorAcc(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_energyPos(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_energyVel(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_checkPos(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_checkVel(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_checkAcc(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_kinematics(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_comPos(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_camlight(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_flex(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_tendon(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_transmission(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_crb(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_makeM(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_factorM(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_solveM(
         m: *const mjModel,
         d: *mut mjData,
@@ -3674,7 +3674,7 @@ unsafe extern "C" {
         n: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_solveM2(
         m: *const mjModel,
         d: *mut mjData,
@@ -3684,16 +3684,16 @@ unsafe extern "C" {
         n: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_comVel(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_passive(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_subtreeVel(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_rne(
         m: *const mjModel,
         d: *mut mjData,
@@ -3701,25 +3701,25 @@ unsafe extern "C" {
         result: *mut mjtNum,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_rnePostConstraint(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_collision(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_makeConstraint(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_island(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_projectConstraint(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_referenceConstraint(m: *const mjModel, d: *mut mjData);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_constraintUpdate(
         m: *const mjModel,
         d: *mut mjData,
@@ -3728,10 +3728,10 @@ unsafe extern "C" {
         flg_coneHessian: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_stateSize(m: *const mjModel, sig: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_getState(
         m: *const mjModel,
         d: *const mjData,
@@ -3739,7 +3739,7 @@ unsafe extern "C" {
         sig: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_extractState(
         m: *const mjModel,
         src: *const mjtNum,
@@ -3748,7 +3748,7 @@ unsafe extern "C" {
         dstsig: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_setState(
         m: *const mjModel,
         d: *mut mjData,
@@ -3756,7 +3756,7 @@ unsafe extern "C" {
         sig: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_copyState(
         m: *const mjModel,
         src: *const mjData,
@@ -3764,7 +3764,7 @@ unsafe extern "C" {
         sig: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_readCtrl(
         m: *const mjModel,
         d: *const mjData,
@@ -3773,7 +3773,7 @@ unsafe extern "C" {
         interp: ::std::os::raw::c_int,
     ) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_readSensor(
         m: *const mjModel,
         d: *const mjData,
@@ -3783,7 +3783,7 @@ unsafe extern "C" {
         interp: ::std::os::raw::c_int,
     ) -> *const mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_initCtrlHistory(
         m: *const mjModel,
         d: *mut mjData,
@@ -3792,7 +3792,7 @@ unsafe extern "C" {
         values: *const mjtNum,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_initSensorHistory(
         m: *const mjModel,
         d: *mut mjData,
@@ -3802,32 +3802,32 @@ unsafe extern "C" {
         phase: mjtNum,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_setKeyframe(m: *mut mjModel, d: *const mjData, k: ::std::os::raw::c_int);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_addContact(
         m: *const mjModel,
         d: *mut mjData,
         con: *const mjContact,
     ) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_isPyramidal(m: *const mjModel) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_isSparse(m: *const mjModel) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_isDual(m: *const mjModel) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_mulJacVec(m: *const mjModel, d: *const mjData, res: *mut mjtNum, vec: *const mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_mulJacTVec(m: *const mjModel, d: *const mjData, res: *mut mjtNum, vec: *const mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_jac(
         m: *const mjModel,
         d: *const mjData,
@@ -3837,7 +3837,7 @@ unsafe extern "C" {
         body: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_jacBody(
         m: *const mjModel,
         d: *const mjData,
@@ -3846,7 +3846,7 @@ unsafe extern "C" {
         body: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_jacBodyCom(
         m: *const mjModel,
         d: *const mjData,
@@ -3855,7 +3855,7 @@ unsafe extern "C" {
         body: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_jacSubtreeCom(
         m: *const mjModel,
         d: *mut mjData,
@@ -3863,7 +3863,7 @@ unsafe extern "C" {
         body: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_jacGeom(
         m: *const mjModel,
         d: *const mjData,
@@ -3872,7 +3872,7 @@ unsafe extern "C" {
         geom: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_jacSite(
         m: *const mjModel,
         d: *const mjData,
@@ -3881,7 +3881,7 @@ unsafe extern "C" {
         site: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_jacPointAxis(
         m: *const mjModel,
         d: *mut mjData,
@@ -3892,7 +3892,7 @@ unsafe extern "C" {
         body: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_jacDot(
         m: *const mjModel,
         d: *const mjData,
@@ -3902,7 +3902,7 @@ unsafe extern "C" {
         body: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_angmomMat(
         m: *const mjModel,
         d: *mut mjData,
@@ -3910,30 +3910,30 @@ unsafe extern "C" {
         body: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_name2id(
         m: *const mjModel,
         type_: ::std::os::raw::c_int,
         name: *const ::std::os::raw::c_char,
     ) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_id2name(
         m: *const mjModel,
         type_: ::std::os::raw::c_int,
         id: ::std::os::raw::c_int,
     ) -> *const ::std::os::raw::c_char;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_fullM(m: *const mjModel, dst: *mut mjtNum, M: *const mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_mulM(m: *const mjModel, d: *const mjData, res: *mut mjtNum, vec: *const mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_mulM2(m: *const mjModel, d: *const mjData, res: *mut mjtNum, vec: *const mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_addM(
         m: *const mjModel,
         d: *mut mjData,
@@ -3943,7 +3943,7 @@ unsafe extern "C" {
         colind: *mut ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_applyFT(
         m: *const mjModel,
         d: *mut mjData,
@@ -3954,7 +3954,7 @@ unsafe extern "C" {
         qfrc_target: *mut mjtNum,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_objectVelocity(
         m: *const mjModel,
         d: *const mjData,
@@ -3964,7 +3964,7 @@ unsafe extern "C" {
         flg_local: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_objectAcceleration(
         m: *const mjModel,
         d: *const mjData,
@@ -3974,7 +3974,7 @@ unsafe extern "C" {
         flg_local: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_geomDistance(
         m: *const mjModel,
         d: *const mjData,
@@ -3984,7 +3984,7 @@ unsafe extern "C" {
         fromto: *mut mjtNum,
     ) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_contactForce(
         m: *const mjModel,
         d: *const mjData,
@@ -3992,7 +3992,7 @@ unsafe extern "C" {
         result: *mut mjtNum,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_differentiatePos(
         m: *const mjModel,
         qvel: *mut mjtNum,
@@ -4001,13 +4001,13 @@ unsafe extern "C" {
         qpos2: *const mjtNum,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_integratePos(m: *const mjModel, qpos: *mut mjtNum, qvel: *const mjtNum, dt: mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_normalizeQuat(m: *const mjModel, qpos: *mut mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_local2Global(
         d: *mut mjData,
         xpos: *mut mjtNum,
@@ -4018,35 +4018,35 @@ unsafe extern "C" {
         sameframe: mjtByte,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_getTotalmass(m: *const mjModel) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_setTotalmass(m: *mut mjModel, newmass: mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_getPluginConfig(
         m: *const mjModel,
         plugin_id: ::std::os::raw::c_int,
         attrib: *const ::std::os::raw::c_char,
     ) -> *const ::std::os::raw::c_char;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_loadPluginLibrary(path: *const ::std::os::raw::c_char);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_loadAllPluginLibraries(
         directory: *const ::std::os::raw::c_char,
         callback: mjfPluginLibraryLoadCallback,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_version() -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_versionString() -> *const ::std::os::raw::c_char;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_ray(
         m: *const mjModel,
         d: *const mjData,
@@ -4059,7 +4059,7 @@ unsafe extern "C" {
         normal: *mut mjtNum,
     ) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_multiRay(
         m: *const mjModel,
         d: *mut mjData,
@@ -4075,7 +4075,7 @@ unsafe extern "C" {
         cutoff: mjtNum,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_rayHfield(
         m: *const mjModel,
         d: *const mjData,
@@ -4085,7 +4085,7 @@ unsafe extern "C" {
         normal: *mut mjtNum,
     ) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_rayMesh(
         m: *const mjModel,
         d: *const mjData,
@@ -4095,7 +4095,7 @@ unsafe extern "C" {
         normal: *mut mjtNum,
     ) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_rayGeom(
         pos: *const mjtNum,
         mat: *const mjtNum,
@@ -4106,7 +4106,7 @@ unsafe extern "C" {
         normal: *mut mjtNum,
     ) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_rayFlex(
         m: *const mjModel,
         d: *const mjData,
@@ -4122,7 +4122,7 @@ unsafe extern "C" {
         normal: *mut mjtNum,
     ) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_raySkin(
         nface: ::std::os::raw::c_int,
         nvert: ::std::os::raw::c_int,
@@ -4133,16 +4133,16 @@ unsafe extern "C" {
         vertid: *mut ::std::os::raw::c_int,
     ) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_defaultCamera(cam: *mut mjvCamera);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_defaultFreeCamera(m: *const mjModel, cam: *mut mjvCamera);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_defaultPerturb(pert: *mut mjvPerturb);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_room2model(
         modelpos: *mut mjtNum,
         modelquat: *mut mjtNum,
@@ -4151,7 +4151,7 @@ unsafe extern "C" {
         scn: *const mjvScene,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_model2room(
         roompos: *mut mjtNum,
         roomquat: *mut mjtNum,
@@ -4160,7 +4160,7 @@ unsafe extern "C" {
         scn: *const mjvScene,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_cameraInModel(
         headpos: *mut mjtNum,
         forward: *mut mjtNum,
@@ -4168,7 +4168,7 @@ unsafe extern "C" {
         scn: *const mjvScene,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_cameraInRoom(
         headpos: *mut mjtNum,
         forward: *mut mjtNum,
@@ -4176,13 +4176,13 @@ unsafe extern "C" {
         scn: *const mjvScene,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_frustumHeight(scn: *const mjvScene) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_alignToCamera(res: *mut mjtNum, vec: *const mjtNum, forward: *const mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_moveCamera(
         m: *const mjModel,
         action: ::std::os::raw::c_int,
@@ -4192,7 +4192,7 @@ unsafe extern "C" {
         cam: *mut mjvCamera,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_movePerturb(
         m: *const mjModel,
         d: *const mjData,
@@ -4203,7 +4203,7 @@ unsafe extern "C" {
         pert: *mut mjvPerturb,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_moveModel(
         m: *const mjModel,
         action: ::std::os::raw::c_int,
@@ -4213,7 +4213,7 @@ unsafe extern "C" {
         scn: *mut mjvScene,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_initPerturb(
         m: *const mjModel,
         d: *mut mjData,
@@ -4221,7 +4221,7 @@ unsafe extern "C" {
         pert: *mut mjvPerturb,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_applyPerturbPose(
         m: *const mjModel,
         d: *mut mjData,
@@ -4229,13 +4229,13 @@ unsafe extern "C" {
         flg_paused: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_applyPerturbForce(m: *const mjModel, d: *mut mjData, pert: *const mjvPerturb);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_averageCamera(cam1: *const mjvGLCamera, cam2: *const mjvGLCamera) -> mjvGLCamera;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_select(
         m: *const mjModel,
         d: *const mjData,
@@ -4250,13 +4250,13 @@ unsafe extern "C" {
         skinid: *mut ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_defaultOption(opt: *mut mjvOption);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_defaultFigure(fig: *mut mjvFigure);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_initGeom(
         geom: *mut mjvGeom,
         type_: ::std::os::raw::c_int,
@@ -4266,7 +4266,7 @@ unsafe extern "C" {
         rgba: *const f32,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_connector(
         geom: *mut mjvGeom,
         type_: ::std::os::raw::c_int,
@@ -4275,16 +4275,16 @@ unsafe extern "C" {
         to: *const mjtNum,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_defaultScene(scn: *mut mjvScene);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_makeScene(m: *const mjModel, scn: *mut mjvScene, maxgeom: ::std::os::raw::c_int);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_freeScene(scn: *mut mjvScene);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_updateScene(
         m: *const mjModel,
         d: *mut mjData,
@@ -4295,10 +4295,10 @@ unsafe extern "C" {
         scn: *mut mjvScene,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_copyModel(dest: *mut mjModel, src: *const mjModel);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_addGeoms(
         m: *const mjModel,
         d: *mut mjData,
@@ -4308,10 +4308,10 @@ unsafe extern "C" {
         scn: *mut mjvScene,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_makeLights(m: *const mjModel, d: *const mjData, scn: *mut mjvScene);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_updateCamera(
         m: *const mjModel,
         d: *const mjData,
@@ -4319,10 +4319,10 @@ unsafe extern "C" {
         scn: *mut mjvScene,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_updateSkin(m: *const mjModel, d: *const mjData, scn: *mut mjvScene);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_cameraFrame(
         headpos: *mut mjtNum,
         forward: *mut mjtNum,
@@ -4332,7 +4332,7 @@ unsafe extern "C" {
         cam: *const mjvCamera,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjv_cameraFrustum(
         zver: *mut f32,
         zhor: *mut f32,
@@ -4341,20 +4341,20 @@ unsafe extern "C" {
         cam: *const mjvCamera,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjr_defaultContext(con: *mut mjrContext);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjr_makeContext(
         m: *const mjModel,
         con: *mut mjrContext,
         fontscale: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjr_changeFont(fontscale: ::std::os::raw::c_int, con: *mut mjrContext);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjr_addAux(
         index: ::std::os::raw::c_int,
         width: ::std::os::raw::c_int,
@@ -4363,40 +4363,40 @@ unsafe extern "C" {
         con: *mut mjrContext,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjr_freeContext(con: *mut mjrContext);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjr_resizeOffscreen(
         width: ::std::os::raw::c_int,
         height: ::std::os::raw::c_int,
         con: *mut mjrContext,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjr_uploadTexture(
         m: *const mjModel,
         con: *const mjrContext,
         texid: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjr_uploadMesh(m: *const mjModel, con: *const mjrContext, meshid: ::std::os::raw::c_int);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjr_uploadHField(
         m: *const mjModel,
         con: *const mjrContext,
         hfieldid: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjr_restoreBuffer(con: *const mjrContext);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjr_setBuffer(framebuffer: ::std::os::raw::c_int, con: *mut mjrContext);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjr_readPixels(
         rgb: *mut ::std::os::raw::c_uchar,
         depth: *mut f32,
@@ -4404,7 +4404,7 @@ unsafe extern "C" {
         con: *const mjrContext,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjr_drawPixels(
         rgb: *const ::std::os::raw::c_uchar,
         depth: *const f32,
@@ -4412,7 +4412,7 @@ unsafe extern "C" {
         con: *const mjrContext,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjr_blitBuffer(
         src: mjrRect,
         dst: mjrRect,
@@ -4421,10 +4421,10 @@ unsafe extern "C" {
         con: *const mjrContext,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjr_setAux(index: ::std::os::raw::c_int, con: *const mjrContext);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjr_blitAux(
         index: ::std::os::raw::c_int,
         src: mjrRect,
@@ -4433,7 +4433,7 @@ unsafe extern "C" {
         con: *const mjrContext,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjr_text(
         font: ::std::os::raw::c_int,
         txt: *const ::std::os::raw::c_char,
@@ -4445,7 +4445,7 @@ unsafe extern "C" {
         b: f32,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjr_overlay(
         font: ::std::os::raw::c_int,
         gridpos: ::std::os::raw::c_int,
@@ -4455,13 +4455,13 @@ unsafe extern "C" {
         con: *const mjrContext,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjr_maxViewport(con: *const mjrContext) -> mjrRect;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjr_rectangle(viewport: mjrRect, r: f32, g: f32, b: f32, a: f32);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjr_label(
         viewport: mjrRect,
         font: ::std::os::raw::c_int,
@@ -4476,19 +4476,19 @@ unsafe extern "C" {
         con: *const mjrContext,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjr_figure(viewport: mjrRect, fig: *mut mjvFigure, con: *const mjrContext);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjr_render(viewport: mjrRect, scn: *mut mjvScene, con: *const mjrContext);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjr_finish();
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjr_getError() -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjr_findRect(
         x: ::std::os::raw::c_int,
         y: ::std::os::raw::c_int,
@@ -4496,22 +4496,22 @@ unsafe extern "C" {
         rect: *const mjrRect,
     ) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjui_themeSpacing(ind: ::std::os::raw::c_int) -> mjuiThemeSpacing;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjui_themeColor(ind: ::std::os::raw::c_int) -> mjuiThemeColor;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjui_add(ui: *mut mjUI, def: *const mjuiDef);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjui_addToSection(ui: *mut mjUI, sect: ::std::os::raw::c_int, def: *const mjuiDef);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjui_resize(ui: *mut mjUI, con: *const mjrContext);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjui_update(
         section: ::std::os::raw::c_int,
         item: ::std::os::raw::c_int,
@@ -4520,134 +4520,134 @@ unsafe extern "C" {
         con: *const mjrContext,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjui_event(
         ui: *mut mjUI,
         state: *mut mjuiState,
         con: *const mjrContext,
     ) -> *mut mjuiItem;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjui_render(ui: *mut mjUI, state: *const mjuiState, con: *const mjrContext);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_error(msg: *const ::std::os::raw::c_char, ...);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_error_i(msg: *const ::std::os::raw::c_char, i: ::std::os::raw::c_int);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_error_s(msg: *const ::std::os::raw::c_char, text: *const ::std::os::raw::c_char);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_warning(msg: *const ::std::os::raw::c_char, ...);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_warning_i(msg: *const ::std::os::raw::c_char, i: ::std::os::raw::c_int);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_warning_s(msg: *const ::std::os::raw::c_char, text: *const ::std::os::raw::c_char);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_clearHandlers();
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_malloc(size: usize) -> *mut ::std::os::raw::c_void;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_free(ptr: *mut ::std::os::raw::c_void);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mj_warning(d: *mut mjData, warning: ::std::os::raw::c_int, info: ::std::os::raw::c_int);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_writeLog(type_: *const ::std::os::raw::c_char, msg: *const ::std::os::raw::c_char);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_getError(s: *mut mjSpec) -> *const ::std::os::raw::c_char;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_isWarning(s: *mut mjSpec) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_zero3(res: *mut mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_copy3(res: *mut mjtNum, data: *const mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_scl3(res: *mut mjtNum, vec: *const mjtNum, scl: mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_add3(res: *mut mjtNum, vec1: *const mjtNum, vec2: *const mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_sub3(res: *mut mjtNum, vec1: *const mjtNum, vec2: *const mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_addTo3(res: *mut mjtNum, vec: *const mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_subFrom3(res: *mut mjtNum, vec: *const mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_addToScl3(res: *mut mjtNum, vec: *const mjtNum, scl: mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_addScl3(res: *mut mjtNum, vec1: *const mjtNum, vec2: *const mjtNum, scl: mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_normalize3(vec: *mut mjtNum) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_norm3(vec: *const mjtNum) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_dot3(vec1: *const mjtNum, vec2: *const mjtNum) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_dist3(pos1: *const mjtNum, pos2: *const mjtNum) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_mulMatVec3(res: *mut mjtNum, mat: *const mjtNum, vec: *const mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_mulMatTVec3(res: *mut mjtNum, mat: *const mjtNum, vec: *const mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_cross(res: *mut mjtNum, a: *const mjtNum, b: *const mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_zero4(res: *mut mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_unit4(res: *mut mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_copy4(res: *mut mjtNum, data: *const mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_normalize4(vec: *mut mjtNum) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_zero(res: *mut mjtNum, n: ::std::os::raw::c_int);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_fill(res: *mut mjtNum, val: mjtNum, n: ::std::os::raw::c_int);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_copy(res: *mut mjtNum, vec: *const mjtNum, n: ::std::os::raw::c_int);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_sum(vec: *const mjtNum, n: ::std::os::raw::c_int) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_L1(vec: *const mjtNum, n: ::std::os::raw::c_int) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_scl(res: *mut mjtNum, vec: *const mjtNum, scl: mjtNum, n: ::std::os::raw::c_int);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_add(
         res: *mut mjtNum,
         vec1: *const mjtNum,
@@ -4655,7 +4655,7 @@ unsafe extern "C" {
         n: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_sub(
         res: *mut mjtNum,
         vec1: *const mjtNum,
@@ -4663,13 +4663,13 @@ unsafe extern "C" {
         n: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_addTo(res: *mut mjtNum, vec: *const mjtNum, n: ::std::os::raw::c_int);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_subFrom(res: *mut mjtNum, vec: *const mjtNum, n: ::std::os::raw::c_int);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_addToScl(
         res: *mut mjtNum,
         vec: *const mjtNum,
@@ -4677,7 +4677,7 @@ unsafe extern "C" {
         n: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_addScl(
         res: *mut mjtNum,
         vec1: *const mjtNum,
@@ -4686,16 +4686,16 @@ unsafe extern "C" {
         n: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_normalize(res: *mut mjtNum, n: ::std::os::raw::c_int) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_norm(res: *const mjtNum, n: ::std::os::raw::c_int) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_dot(vec1: *const mjtNum, vec2: *const mjtNum, n: ::std::os::raw::c_int) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_mulMatVec(
         res: *mut mjtNum,
         mat: *const mjtNum,
@@ -4704,7 +4704,7 @@ unsafe extern "C" {
         nc: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_mulMatTVec(
         res: *mut mjtNum,
         mat: *const mjtNum,
@@ -4713,7 +4713,7 @@ unsafe extern "C" {
         nc: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_mulVecMatVec(
         vec1: *const mjtNum,
         mat: *const mjtNum,
@@ -4721,7 +4721,7 @@ unsafe extern "C" {
         n: ::std::os::raw::c_int,
     ) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_transpose(
         res: *mut mjtNum,
         mat: *const mjtNum,
@@ -4729,13 +4729,13 @@ unsafe extern "C" {
         nc: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_symmetrize(res: *mut mjtNum, mat: *const mjtNum, n: ::std::os::raw::c_int);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_eye(mat: *mut mjtNum, n: ::std::os::raw::c_int);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_mulMatMat(
         res: *mut mjtNum,
         mat1: *const mjtNum,
@@ -4745,7 +4745,7 @@ unsafe extern "C" {
         c2: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_mulMatMatT(
         res: *mut mjtNum,
         mat1: *const mjtNum,
@@ -4755,7 +4755,7 @@ unsafe extern "C" {
         r2: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_mulMatTMat(
         res: *mut mjtNum,
         mat1: *const mjtNum,
@@ -4765,7 +4765,7 @@ unsafe extern "C" {
         c2: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_sqrMatTD(
         res: *mut mjtNum,
         mat: *const mjtNum,
@@ -4774,7 +4774,7 @@ unsafe extern "C" {
         nc: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_transformSpatial(
         res: *mut mjtNum,
         vec: *const mjtNum,
@@ -4784,7 +4784,7 @@ unsafe extern "C" {
         rotnew2old: *const mjtNum,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_dense2sparse(
         res: *mut mjtNum,
         mat: *const mjtNum,
@@ -4796,7 +4796,7 @@ unsafe extern "C" {
         nnz: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_sparse2dense(
         res: *mut mjtNum,
         mat: *const mjtNum,
@@ -4807,53 +4807,53 @@ unsafe extern "C" {
         colind: *const ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_rotVecQuat(res: *mut mjtNum, vec: *const mjtNum, quat: *const mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_negQuat(res: *mut mjtNum, quat: *const mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_mulQuat(res: *mut mjtNum, quat1: *const mjtNum, quat2: *const mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_mulQuatAxis(res: *mut mjtNum, quat: *const mjtNum, axis: *const mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_axisAngle2Quat(res: *mut mjtNum, axis: *const mjtNum, angle: mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_quat2Vel(res: *mut mjtNum, quat: *const mjtNum, dt: mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_subQuat(res: *mut mjtNum, qa: *const mjtNum, qb: *const mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_quat2Mat(res: *mut mjtNum, quat: *const mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_mat2Quat(quat: *mut mjtNum, mat: *const mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_derivQuat(res: *mut mjtNum, quat: *const mjtNum, vel: *const mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_quatIntegrate(quat: *mut mjtNum, vel: *const mjtNum, scale: mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_quatZ2Vec(quat: *mut mjtNum, vec: *const mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_mat2Rot(quat: *mut mjtNum, mat: *const mjtNum) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_euler2Quat(
         quat: *mut mjtNum,
         euler: *const mjtNum,
         seq: *const ::std::os::raw::c_char,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_mulPose(
         posres: *mut mjtNum,
         quatres: *mut mjtNum,
@@ -4863,7 +4863,7 @@ unsafe extern "C" {
         quat2: *const mjtNum,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_negPose(
         posres: *mut mjtNum,
         quatres: *mut mjtNum,
@@ -4871,7 +4871,7 @@ unsafe extern "C" {
         quat: *const mjtNum,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_trnVecPose(
         res: *mut mjtNum,
         pos: *const mjtNum,
@@ -4879,14 +4879,14 @@ unsafe extern "C" {
         vec: *const mjtNum,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_cholFactor(
         mat: *mut mjtNum,
         n: ::std::os::raw::c_int,
         mindiag: mjtNum,
     ) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_cholSolve(
         res: *mut mjtNum,
         mat: *const mjtNum,
@@ -4894,7 +4894,7 @@ unsafe extern "C" {
         n: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_cholUpdate(
         mat: *mut mjtNum,
         x: *mut mjtNum,
@@ -4902,7 +4902,7 @@ unsafe extern "C" {
         flg_plus: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_cholFactorBand(
         mat: *mut mjtNum,
         ntotal: ::std::os::raw::c_int,
@@ -4912,7 +4912,7 @@ unsafe extern "C" {
         diagmul: mjtNum,
     ) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_cholSolveBand(
         res: *mut mjtNum,
         mat: *const mjtNum,
@@ -4922,7 +4922,7 @@ unsafe extern "C" {
         ndense: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_band2Dense(
         res: *mut mjtNum,
         mat: *const mjtNum,
@@ -4932,7 +4932,7 @@ unsafe extern "C" {
         flg_sym: mjtByte,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_dense2Band(
         res: *mut mjtNum,
         mat: *const mjtNum,
@@ -4941,7 +4941,7 @@ unsafe extern "C" {
         ndense: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_bandMulMatVec(
         res: *mut mjtNum,
         mat: *const mjtNum,
@@ -4953,7 +4953,7 @@ unsafe extern "C" {
         flg_sym: mjtByte,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_bandDiag(
         i: ::std::os::raw::c_int,
         ntotal: ::std::os::raw::c_int,
@@ -4961,7 +4961,7 @@ unsafe extern "C" {
         ndense: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_eig3(
         eigval: *mut mjtNum,
         eigvec: *mut mjtNum,
@@ -4969,7 +4969,7 @@ unsafe extern "C" {
         mat: *const mjtNum,
     ) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_boxQP(
         res: *mut mjtNum,
         R: *mut mjtNum,
@@ -4981,7 +4981,7 @@ unsafe extern "C" {
         upper: *const mjtNum,
     ) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_boxQPmalloc(
         res: *mut *mut mjtNum,
         R: *mut *mut mjtNum,
@@ -4993,7 +4993,7 @@ unsafe extern "C" {
         upper: *mut *mut mjtNum,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_muscleGain(
         len: mjtNum,
         vel: mjtNum,
@@ -5002,7 +5002,7 @@ unsafe extern "C" {
         prm: *const mjtNum,
     ) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_muscleBias(
         len: mjtNum,
         lengthrange: *const mjtNum,
@@ -5010,10 +5010,10 @@ unsafe extern "C" {
         prm: *const mjtNum,
     ) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_muscleDynamics(ctrl: mjtNum, act: mjtNum, prm: *const mjtNum) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_encodePyramid(
         pyramid: *mut mjtNum,
         force: *const mjtNum,
@@ -5021,7 +5021,7 @@ unsafe extern "C" {
         dim: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_decodePyramid(
         force: *mut mjtNum,
         pyramid: *const mjtNum,
@@ -5029,7 +5029,7 @@ unsafe extern "C" {
         dim: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_springDamper(
         pos0: mjtNum,
         vel0: mjtNum,
@@ -5038,80 +5038,80 @@ unsafe extern "C" {
         dt: mjtNum,
     ) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_min(a: mjtNum, b: mjtNum) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_max(a: mjtNum, b: mjtNum) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_clip(x: mjtNum, min: mjtNum, max: mjtNum) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_sign(x: mjtNum) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_round(x: mjtNum) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_type2Str(type_: ::std::os::raw::c_int) -> *const ::std::os::raw::c_char;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_str2Type(str_: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_writeNumBytes(nbytes: usize) -> *const ::std::os::raw::c_char;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_warningText(
         warning: ::std::os::raw::c_int,
         info: usize,
     ) -> *const ::std::os::raw::c_char;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_isBad(x: mjtNum) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_isZero(vec: *const mjtNum, n: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_standardNormal(num2: *mut mjtNum) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_f2n(res: *mut mjtNum, vec: *const f32, n: ::std::os::raw::c_int);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_n2f(res: *mut f32, vec: *const mjtNum, n: ::std::os::raw::c_int);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_d2n(res: *mut mjtNum, vec: *const f64, n: ::std::os::raw::c_int);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_n2d(res: *mut f64, vec: *const mjtNum, n: ::std::os::raw::c_int);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_insertionSort(list: *mut mjtNum, n: ::std::os::raw::c_int);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_insertionSortInt(list: *mut ::std::os::raw::c_int, n: ::std::os::raw::c_int);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_Halton(index: ::std::os::raw::c_int, base: ::std::os::raw::c_int) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_strncpy(
         dst: *mut ::std::os::raw::c_char,
         src: *const ::std::os::raw::c_char,
         n: ::std::os::raw::c_int,
     ) -> *mut ::std::os::raw::c_char;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_sigmoid(x: mjtNum) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjc_getSDF(m: *const mjModel, id: ::std::os::raw::c_int) -> *const mjpPlugin;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjc_distance(
         m: *const mjModel,
         d: *const mjData,
@@ -5119,7 +5119,7 @@ unsafe extern "C" {
         x: *const mjtNum,
     ) -> mjtNum;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjc_gradient(
         m: *const mjModel,
         d: *const mjData,
@@ -5128,7 +5128,7 @@ unsafe extern "C" {
         x: *const mjtNum,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjd_transitionFD(
         m: *const mjModel,
         d: *mut mjData,
@@ -5140,7 +5140,7 @@ unsafe extern "C" {
         D: *mut mjtNum,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjd_inverseFD(
         m: *const mjModel,
         d: *mut mjData,
@@ -5155,10 +5155,10 @@ unsafe extern "C" {
         DmDq: *mut mjtNum,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjd_subQuat(qa: *const mjtNum, qb: *const mjtNum, Da: *mut mjtNum, Db: *mut mjtNum);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjd_quatIntegrate(
         vel: *const mjtNum,
         scale: mjtNum,
@@ -5167,57 +5167,57 @@ unsafe extern "C" {
         Dscale: *mut mjtNum,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjp_defaultPlugin(plugin: *mut mjpPlugin);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjp_registerPlugin(plugin: *const mjpPlugin) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjp_pluginCount() -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjp_getPlugin(
         name: *const ::std::os::raw::c_char,
         slot: *mut ::std::os::raw::c_int,
     ) -> *const mjpPlugin;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjp_getPluginAtSlot(slot: ::std::os::raw::c_int) -> *const mjpPlugin;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjp_defaultResourceProvider(provider: *mut mjpResourceProvider);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjp_registerResourceProvider(
         provider: *const mjpResourceProvider,
     ) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjp_resourceProviderCount() -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjp_getResourceProvider(
         resource_name: *const ::std::os::raw::c_char,
     ) -> *const mjpResourceProvider;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjp_getResourceProviderAtSlot(slot: ::std::os::raw::c_int)
     -> *const mjpResourceProvider;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjp_registerDecoder(decoder: *const mjpDecoder);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjp_defaultDecoder(decoder: *mut mjpDecoder);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjp_findDecoder(
         resource: *const mjResource,
         content_type: *const ::std::os::raw::c_char,
     ) -> *const mjpDecoder;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_openResource(
         dir: *const ::std::os::raw::c_char,
         name: *const ::std::os::raw::c_char,
@@ -5226,54 +5226,54 @@ unsafe extern "C" {
         nerror: usize,
     ) -> *mut mjResource;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_closeResource(resource: *mut mjResource);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_readResource(
         resource: *mut mjResource,
         buffer: *mut *const ::std::os::raw::c_void,
     ) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_getResourceDir(
         resource: *mut mjResource,
         dir: *mut *const ::std::os::raw::c_char,
         ndir: *mut ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_isModifiedResource(
         resource: *const mjResource,
         timestamp: *const ::std::os::raw::c_char,
     ) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_decodeResource(
         resource: *mut mjResource,
         content_type: *const ::std::os::raw::c_char,
         vfs: *const mjVFS,
     ) -> *mut mjSpec;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_threadPoolCreate(number_of_threads: usize) -> *mut mjThreadPool;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_bindThreadPool(d: *mut mjData, thread_pool: *mut ::std::os::raw::c_void);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_threadPoolEnqueue(thread_pool: *mut mjThreadPool, task: *mut mjTask);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_threadPoolDestroy(thread_pool: *mut mjThreadPool);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_defaultTask(task: *mut mjTask);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mju_taskJoin(task: *mut mjTask);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_attach(
         parent: *mut mjsElement,
         child: *const mjsElement,
@@ -5281,103 +5281,103 @@ unsafe extern "C" {
         suffix: *const ::std::os::raw::c_char,
     ) -> *mut mjsElement;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_addBody(body: *mut mjsBody, def: *const mjsDefault) -> *mut mjsBody;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_addSite(body: *mut mjsBody, def: *const mjsDefault) -> *mut mjsSite;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_addJoint(body: *mut mjsBody, def: *const mjsDefault) -> *mut mjsJoint;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_addFreeJoint(body: *mut mjsBody) -> *mut mjsJoint;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_addGeom(body: *mut mjsBody, def: *const mjsDefault) -> *mut mjsGeom;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_addCamera(body: *mut mjsBody, def: *const mjsDefault) -> *mut mjsCamera;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_addLight(body: *mut mjsBody, def: *const mjsDefault) -> *mut mjsLight;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_addFrame(body: *mut mjsBody, parentframe: *mut mjsFrame) -> *mut mjsFrame;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_delete(spec: *mut mjSpec, element: *mut mjsElement) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_addActuator(s: *mut mjSpec, def: *const mjsDefault) -> *mut mjsActuator;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_addSensor(s: *mut mjSpec) -> *mut mjsSensor;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_addFlex(s: *mut mjSpec) -> *mut mjsFlex;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_addPair(s: *mut mjSpec, def: *const mjsDefault) -> *mut mjsPair;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_addExclude(s: *mut mjSpec) -> *mut mjsExclude;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_addEquality(s: *mut mjSpec, def: *const mjsDefault) -> *mut mjsEquality;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_addTendon(s: *mut mjSpec, def: *const mjsDefault) -> *mut mjsTendon;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_wrapSite(
         tendon: *mut mjsTendon,
         name: *const ::std::os::raw::c_char,
     ) -> *mut mjsWrap;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_wrapGeom(
         tendon: *mut mjsTendon,
         name: *const ::std::os::raw::c_char,
         sidesite: *const ::std::os::raw::c_char,
     ) -> *mut mjsWrap;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_wrapJoint(
         tendon: *mut mjsTendon,
         name: *const ::std::os::raw::c_char,
         coef: f64,
     ) -> *mut mjsWrap;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_wrapPulley(tendon: *mut mjsTendon, divisor: f64) -> *mut mjsWrap;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_addNumeric(s: *mut mjSpec) -> *mut mjsNumeric;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_addText(s: *mut mjSpec) -> *mut mjsText;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_addTuple(s: *mut mjSpec) -> *mut mjsTuple;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_addKey(s: *mut mjSpec) -> *mut mjsKey;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_addPlugin(s: *mut mjSpec) -> *mut mjsPlugin;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_addDefault(
         s: *mut mjSpec,
         classname: *const ::std::os::raw::c_char,
         parent: *const mjsDefault,
     ) -> *mut mjsDefault;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_setToMotor(actuator: *mut mjsActuator) -> *const ::std::os::raw::c_char;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_setToPosition(
         actuator: *mut mjsActuator,
         kp: f64,
@@ -5387,7 +5387,7 @@ unsafe extern "C" {
         inheritrange: f64,
     ) -> *const ::std::os::raw::c_char;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_setToIntVelocity(
         actuator: *mut mjsActuator,
         kp: f64,
@@ -5397,13 +5397,13 @@ unsafe extern "C" {
         inheritrange: f64,
     ) -> *const ::std::os::raw::c_char;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_setToVelocity(actuator: *mut mjsActuator, kv: f64) -> *const ::std::os::raw::c_char;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_setToDamper(actuator: *mut mjsActuator, kv: f64) -> *const ::std::os::raw::c_char;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_setToCylinder(
         actuator: *mut mjsActuator,
         timeconst: f64,
@@ -5412,7 +5412,7 @@ unsafe extern "C" {
         diameter: f64,
     ) -> *const ::std::os::raw::c_char;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_setToMuscle(
         actuator: *mut mjsActuator,
         timeconst: *mut f64,
@@ -5427,28 +5427,28 @@ unsafe extern "C" {
         fvmax: f64,
     ) -> *const ::std::os::raw::c_char;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_setToAdhesion(
         actuator: *mut mjsActuator,
         gain: f64,
     ) -> *const ::std::os::raw::c_char;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_addMesh(s: *mut mjSpec, def: *const mjsDefault) -> *mut mjsMesh;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_addHField(s: *mut mjSpec) -> *mut mjsHField;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_addSkin(s: *mut mjSpec) -> *mut mjsSkin;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_addTexture(s: *mut mjSpec) -> *mut mjsTexture;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_addMaterial(s: *mut mjSpec, def: *const mjsDefault) -> *mut mjsMaterial;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_makeMesh(
         mesh: *mut mjsMesh,
         builtin: mjtMeshBuiltin,
@@ -5456,168 +5456,168 @@ unsafe extern "C" {
         nparams: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_getSpec(element: *mut mjsElement) -> *mut mjSpec;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_findSpec(spec: *mut mjSpec, name: *const ::std::os::raw::c_char) -> *mut mjSpec;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_findBody(s: *mut mjSpec, name: *const ::std::os::raw::c_char) -> *mut mjsBody;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_findElement(
         s: *mut mjSpec,
         type_: mjtObj,
         name: *const ::std::os::raw::c_char,
     ) -> *mut mjsElement;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_findChild(body: *mut mjsBody, name: *const ::std::os::raw::c_char) -> *mut mjsBody;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_getParent(element: *mut mjsElement) -> *mut mjsBody;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_getFrame(element: *mut mjsElement) -> *mut mjsFrame;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_findFrame(s: *mut mjSpec, name: *const ::std::os::raw::c_char) -> *mut mjsFrame;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_getDefault(element: *mut mjsElement) -> *mut mjsDefault;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_findDefault(
         s: *mut mjSpec,
         classname: *const ::std::os::raw::c_char,
     ) -> *mut mjsDefault;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_getSpecDefault(s: *mut mjSpec) -> *mut mjsDefault;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_getId(element: *mut mjsElement) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_firstChild(
         body: *mut mjsBody,
         type_: mjtObj,
         recurse: ::std::os::raw::c_int,
     ) -> *mut mjsElement;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_nextChild(
         body: *mut mjsBody,
         child: *mut mjsElement,
         recurse: ::std::os::raw::c_int,
     ) -> *mut mjsElement;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_firstElement(s: *mut mjSpec, type_: mjtObj) -> *mut mjsElement;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_nextElement(s: *mut mjSpec, element: *mut mjsElement) -> *mut mjsElement;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_getWrapTarget(wrap: *mut mjsWrap) -> *mut mjsElement;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_getWrapSideSite(wrap: *mut mjsWrap) -> *mut mjsSite;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_getWrapDivisor(wrap: *mut mjsWrap) -> f64;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_getWrapCoef(wrap: *mut mjsWrap) -> f64;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_setName(
         element: *mut mjsElement,
         name: *const ::std::os::raw::c_char,
     ) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_setBuffer(
         dest: *mut mjByteVec,
         array: *const ::std::os::raw::c_void,
         size: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_setString(dest: *mut mjString, text: *const ::std::os::raw::c_char);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_setStringVec(dest: *mut mjStringVec, text: *const ::std::os::raw::c_char);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_setInStringVec(
         dest: *mut mjStringVec,
         i: ::std::os::raw::c_int,
         text: *const ::std::os::raw::c_char,
     ) -> mjtByte;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_appendString(dest: *mut mjStringVec, text: *const ::std::os::raw::c_char);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_setInt(
         dest: *mut mjIntVec,
         array: *const ::std::os::raw::c_int,
         size: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_appendIntVec(
         dest: *mut mjIntVecVec,
         array: *const ::std::os::raw::c_int,
         size: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_setFloat(dest: *mut mjFloatVec, array: *const f32, size: ::std::os::raw::c_int);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_appendFloatVec(
         dest: *mut mjFloatVecVec,
         array: *const f32,
         size: ::std::os::raw::c_int,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_setDouble(dest: *mut mjDoubleVec, array: *const f64, size: ::std::os::raw::c_int);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_setPluginAttributes(plugin: *mut mjsPlugin, attributes: *mut ::std::os::raw::c_void);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_getName(element: *mut mjsElement) -> *mut mjString;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_getString(source: *const mjString) -> *const ::std::os::raw::c_char;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_getDouble(
         source: *const mjDoubleVec,
         size: *mut ::std::os::raw::c_int,
     ) -> *const f64;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_getWrapNum(tendonspec: *const mjsTendon) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_getWrap(tendonspec: *const mjsTendon, i: ::std::os::raw::c_int) -> *mut mjsWrap;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_getPluginAttributes(plugin: *const mjsPlugin) -> *const ::std::os::raw::c_void;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_setDefault(element: *mut mjsElement, def: *const mjsDefault);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_setFrame(dest: *mut mjsElement, frame: *mut mjsFrame) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_resolveOrientation(
         quat: *mut f64,
         degree: mjtByte,
@@ -5625,17 +5625,17 @@ unsafe extern "C" {
         orientation: *const mjsOrientation,
     ) -> *const ::std::os::raw::c_char;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_bodyToFrame(body: *mut *mut mjsBody) -> *mut mjsFrame;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_setUserValue(
         element: *mut mjsElement,
         key: *const ::std::os::raw::c_char,
         data: *const ::std::os::raw::c_void,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_setUserValueWithCleanup(
         element: *mut mjsElement,
         key: *const ::std::os::raw::c_char,
@@ -5643,163 +5643,163 @@ unsafe extern "C" {
         cleanup: ::std::option::Option<unsafe extern "C" fn(arg1: *const ::std::os::raw::c_void)>,
     );
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_getUserValue(
         element: *mut mjsElement,
         key: *const ::std::os::raw::c_char,
     ) -> *const ::std::os::raw::c_void;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_deleteUserValue(element: *mut mjsElement, key: *const ::std::os::raw::c_char);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_sensorDim(sensor: *const mjsSensor) -> ::std::os::raw::c_int;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_defaultSpec(spec: *mut mjSpec);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_defaultOrientation(orient: *mut mjsOrientation);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_defaultBody(body: *mut mjsBody);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_defaultFrame(frame: *mut mjsFrame);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_defaultJoint(joint: *mut mjsJoint);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_defaultGeom(geom: *mut mjsGeom);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_defaultSite(site: *mut mjsSite);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_defaultCamera(camera: *mut mjsCamera);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_defaultLight(light: *mut mjsLight);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_defaultFlex(flex: *mut mjsFlex);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_defaultMesh(mesh: *mut mjsMesh);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_defaultHField(hfield: *mut mjsHField);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_defaultSkin(skin: *mut mjsSkin);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_defaultTexture(texture: *mut mjsTexture);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_defaultMaterial(material: *mut mjsMaterial);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_defaultPair(pair: *mut mjsPair);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_defaultEquality(equality: *mut mjsEquality);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_defaultTendon(tendon: *mut mjsTendon);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_defaultActuator(actuator: *mut mjsActuator);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_defaultSensor(sensor: *mut mjsSensor);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_defaultNumeric(numeric: *mut mjsNumeric);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_defaultText(text: *mut mjsText);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_defaultTuple(tuple: *mut mjsTuple);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_defaultKey(key: *mut mjsKey);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_defaultPlugin(plugin: *mut mjsPlugin);
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_asBody(element: *mut mjsElement) -> *mut mjsBody;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_asGeom(element: *mut mjsElement) -> *mut mjsGeom;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_asJoint(element: *mut mjsElement) -> *mut mjsJoint;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_asSite(element: *mut mjsElement) -> *mut mjsSite;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_asCamera(element: *mut mjsElement) -> *mut mjsCamera;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_asLight(element: *mut mjsElement) -> *mut mjsLight;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_asFrame(element: *mut mjsElement) -> *mut mjsFrame;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_asActuator(element: *mut mjsElement) -> *mut mjsActuator;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_asSensor(element: *mut mjsElement) -> *mut mjsSensor;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_asFlex(element: *mut mjsElement) -> *mut mjsFlex;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_asPair(element: *mut mjsElement) -> *mut mjsPair;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_asEquality(element: *mut mjsElement) -> *mut mjsEquality;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_asExclude(element: *mut mjsElement) -> *mut mjsExclude;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_asTendon(element: *mut mjsElement) -> *mut mjsTendon;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_asNumeric(element: *mut mjsElement) -> *mut mjsNumeric;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_asText(element: *mut mjsElement) -> *mut mjsText;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_asTuple(element: *mut mjsElement) -> *mut mjsTuple;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_asKey(element: *mut mjsElement) -> *mut mjsKey;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_asMesh(element: *mut mjsElement) -> *mut mjsMesh;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_asHField(element: *mut mjsElement) -> *mut mjsHField;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_asSkin(element: *mut mjsElement) -> *mut mjsSkin;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_asTexture(element: *mut mjsElement) -> *mut mjsTexture;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_asMaterial(element: *mut mjsElement) -> *mut mjsMaterial;
 }
-unsafe extern "C" {
+unsafe extern "C-unwind" {
     pub fn mjs_asPlugin(element: *mut mjsElement) -> *mut mjsPlugin;
 }
 // === GENERATED BY generate_from_introspect.py - DO NOT EDIT BELOW THIS LINE ===
@@ -5844,3 +5844,365 @@ impl From<usize> for mjtJoint {
         }
     }
 }
+
+impl TryFrom<i32> for mjtSleepPolicy {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(mjtSleepPolicy::mjSLEEP_AUTO),
+            1 => Ok(mjtSleepPolicy::mjSLEEP_AUTO_NEVER),
+            2 => Ok(mjtSleepPolicy::mjSLEEP_AUTO_ALLOWED),
+            3 => Ok(mjtSleepPolicy::mjSLEEP_NEVER),
+            4 => Ok(mjtSleepPolicy::mjSLEEP_ALLOWED),
+            5 => Ok(mjtSleepPolicy::mjSLEEP_INIT),
+
+            _ => Err(value),
+        }
+    }
+}
+impl TryFrom<i32> for mjtJoint {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(mjtJoint::FREE),
+            1 => Ok(mjtJoint::BALL),
+            2 => Ok(mjtJoint::SLIDE),
+            3 => Ok(mjtJoint::HINGE),
+
+            _ => Err(value),
+        }
+    }
+}
+impl TryFrom<i32> for mjtGeom {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(mjtGeom::PLANE),
+            1 => Ok(mjtGeom::HFIELD),
+            2 => Ok(mjtGeom::SPHERE),
+            3 => Ok(mjtGeom::CAPSULE),
+            4 => Ok(mjtGeom::ELLIPSOID),
+            5 => Ok(mjtGeom::CYLINDER),
+            6 => Ok(mjtGeom::BOX),
+            7 => Ok(mjtGeom::MESH),
+            8 => Ok(mjtGeom::SDF),
+            100 => Ok(mjtGeom::ARROW),
+            101 => Ok(mjtGeom::ARROW1),
+            102 => Ok(mjtGeom::ARROW2),
+            103 => Ok(mjtGeom::LINE),
+            104 => Ok(mjtGeom::LINEBOX),
+            105 => Ok(mjtGeom::FLEX),
+            106 => Ok(mjtGeom::SKIN),
+            107 => Ok(mjtGeom::LABEL),
+            108 => Ok(mjtGeom::TRIANGLE),
+            1001 => Ok(mjtGeom::NONE),
+
+            _ => Err(value),
+        }
+    }
+}
+impl TryFrom<i32> for mjtCamLight {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(mjtCamLight::FIXED),
+            1 => Ok(mjtCamLight::TRACK),
+            2 => Ok(mjtCamLight::TRACKCOM),
+            3 => Ok(mjtCamLight::TARGETBODY),
+            4 => Ok(mjtCamLight::TARGETBODYCOM),
+
+            _ => Err(value),
+        }
+    }
+}
+impl TryFrom<i32> for mjtProjection {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(mjtProjection::mjPROJ_PERSPECTIVE),
+            1 => Ok(mjtProjection::mjPROJ_ORTHOGRAPHIC),
+
+            _ => Err(value),
+        }
+    }
+}
+impl TryFrom<i32> for mjtLightType {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(mjtLightType::SPOT),
+            1 => Ok(mjtLightType::DIRECTIONAL),
+            2 => Ok(mjtLightType::POINT),
+            3 => Ok(mjtLightType::IMAGE),
+
+            _ => Err(value),
+        }
+    }
+}
+impl TryFrom<i32> for mjtFlexSelf {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(mjtFlexSelf::NONE),
+            1 => Ok(mjtFlexSelf::NARROW),
+            2 => Ok(mjtFlexSelf::BVH),
+            3 => Ok(mjtFlexSelf::SAP),
+            4 => Ok(mjtFlexSelf::AUTO),
+
+            _ => Err(value),
+        }
+    }
+}
+impl TryFrom<i32> for mjtTexture {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(mjtTexture::mjTEXTURE_2D),
+            1 => Ok(mjtTexture::CUBE),
+            2 => Ok(mjtTexture::SKYBOX),
+
+            _ => Err(value),
+        }
+    }
+}
+impl TryFrom<i32> for mjtColorSpace {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(mjtColorSpace::AUTO),
+            1 => Ok(mjtColorSpace::LINEAR),
+            2 => Ok(mjtColorSpace::SRGB),
+
+            _ => Err(value),
+        }
+    }
+}
+impl TryFrom<i32> for mjtEq {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(mjtEq::CONNECT),
+            1 => Ok(mjtEq::WELD),
+            2 => Ok(mjtEq::JOINT),
+            3 => Ok(mjtEq::TENDON),
+            4 => Ok(mjtEq::FLEX),
+            5 => Ok(mjtEq::FLEXVERT),
+            6 => Ok(mjtEq::DISTANCE),
+
+            _ => Err(value),
+        }
+    }
+}
+impl TryFrom<i32> for mjtObj {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(mjtObj::UNKNOWN),
+            1 => Ok(mjtObj::BODY),
+            2 => Ok(mjtObj::XBODY),
+            3 => Ok(mjtObj::JOINT),
+            4 => Ok(mjtObj::DOF),
+            5 => Ok(mjtObj::GEOM),
+            6 => Ok(mjtObj::SITE),
+            7 => Ok(mjtObj::CAMERA),
+            8 => Ok(mjtObj::LIGHT),
+            9 => Ok(mjtObj::FLEX),
+            10 => Ok(mjtObj::MESH),
+            11 => Ok(mjtObj::SKIN),
+            12 => Ok(mjtObj::HFIELD),
+            13 => Ok(mjtObj::TEXTURE),
+            14 => Ok(mjtObj::MATERIAL),
+            15 => Ok(mjtObj::PAIR),
+            16 => Ok(mjtObj::EXCLUDE),
+            17 => Ok(mjtObj::EQUALITY),
+            18 => Ok(mjtObj::TENDON),
+            19 => Ok(mjtObj::ACTUATOR),
+            20 => Ok(mjtObj::SENSOR),
+            21 => Ok(mjtObj::NUMERIC),
+            22 => Ok(mjtObj::TEXT),
+            23 => Ok(mjtObj::TUPLE),
+            24 => Ok(mjtObj::KEY),
+            25 => Ok(mjtObj::PLUGIN),
+            100 => Ok(mjtObj::FRAME),
+            101 => Ok(mjtObj::DEFAULT),
+            102 => Ok(mjtObj::MODEL),
+
+            _ => Err(value),
+        }
+    }
+}
+impl TryFrom<i32> for mjtWrap {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(mjtWrap::NONE),
+            1 => Ok(mjtWrap::JOINT),
+            2 => Ok(mjtWrap::PULLEY),
+            3 => Ok(mjtWrap::SITE),
+            4 => Ok(mjtWrap::SPHERE),
+            5 => Ok(mjtWrap::CYLINDER),
+
+            _ => Err(value),
+        }
+    }
+}
+impl TryFrom<i32> for mjtTrn {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(mjtTrn::JOINT),
+            1 => Ok(mjtTrn::JOINTINPARENT),
+            2 => Ok(mjtTrn::SLIDERCRANK),
+            3 => Ok(mjtTrn::TENDON),
+            4 => Ok(mjtTrn::SITE),
+            5 => Ok(mjtTrn::BODY),
+            1000 => Ok(mjtTrn::UNDEFINED),
+
+            _ => Err(value),
+        }
+    }
+}
+impl TryFrom<i32> for mjtDyn {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(mjtDyn::NONE),
+            1 => Ok(mjtDyn::INTEGRATOR),
+            2 => Ok(mjtDyn::FILTER),
+            3 => Ok(mjtDyn::FILTEREXACT),
+            4 => Ok(mjtDyn::MUSCLE),
+            5 => Ok(mjtDyn::USER),
+
+            _ => Err(value),
+        }
+    }
+}
+impl TryFrom<i32> for mjtGain {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(mjtGain::FIXED),
+            1 => Ok(mjtGain::AFFINE),
+            2 => Ok(mjtGain::MUSCLE),
+            3 => Ok(mjtGain::USER),
+
+            _ => Err(value),
+        }
+    }
+}
+impl TryFrom<i32> for mjtBias {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(mjtBias::NONE),
+            1 => Ok(mjtBias::AFFINE),
+            2 => Ok(mjtBias::MUSCLE),
+            3 => Ok(mjtBias::USER),
+
+            _ => Err(value),
+        }
+    }
+}
+impl TryFrom<i32> for mjtSensor {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(mjtSensor::TOUCH),
+            1 => Ok(mjtSensor::ACCELEROMETER),
+            2 => Ok(mjtSensor::VELOCIMETER),
+            3 => Ok(mjtSensor::GYRO),
+            4 => Ok(mjtSensor::FORCE),
+            5 => Ok(mjtSensor::TORQUE),
+            6 => Ok(mjtSensor::MAGNETOMETER),
+            7 => Ok(mjtSensor::RANGEFINDER),
+            8 => Ok(mjtSensor::CAMPROJECTION),
+            9 => Ok(mjtSensor::JOINTPOS),
+            10 => Ok(mjtSensor::JOINTVEL),
+            11 => Ok(mjtSensor::TENDONPOS),
+            12 => Ok(mjtSensor::TENDONVEL),
+            13 => Ok(mjtSensor::ACTUATORPOS),
+            14 => Ok(mjtSensor::ACTUATORVEL),
+            15 => Ok(mjtSensor::ACTUATORFRC),
+            16 => Ok(mjtSensor::JOINTACTFRC),
+            17 => Ok(mjtSensor::TENDONACTFRC),
+            18 => Ok(mjtSensor::BALLQUAT),
+            19 => Ok(mjtSensor::BALLANGVEL),
+            20 => Ok(mjtSensor::JOINTLIMITPOS),
+            21 => Ok(mjtSensor::JOINTLIMITVEL),
+            22 => Ok(mjtSensor::JOINTLIMITFRC),
+            23 => Ok(mjtSensor::TENDONLIMITPOS),
+            24 => Ok(mjtSensor::TENDONLIMITVEL),
+            25 => Ok(mjtSensor::TENDONLIMITFRC),
+            26 => Ok(mjtSensor::FRAMEPOS),
+            27 => Ok(mjtSensor::FRAMEQUAT),
+            28 => Ok(mjtSensor::FRAMEXAXIS),
+            29 => Ok(mjtSensor::FRAMEYAXIS),
+            30 => Ok(mjtSensor::FRAMEZAXIS),
+            31 => Ok(mjtSensor::FRAMELINVEL),
+            32 => Ok(mjtSensor::FRAMEANGVEL),
+            33 => Ok(mjtSensor::FRAMELINACC),
+            34 => Ok(mjtSensor::FRAMEANGACC),
+            35 => Ok(mjtSensor::SUBTREECOM),
+            36 => Ok(mjtSensor::SUBTREELINVEL),
+            37 => Ok(mjtSensor::SUBTREEANGMOM),
+            38 => Ok(mjtSensor::INSIDESITE),
+            39 => Ok(mjtSensor::GEOMDIST),
+            40 => Ok(mjtSensor::GEOMNORMAL),
+            41 => Ok(mjtSensor::GEOMFROMTO),
+            42 => Ok(mjtSensor::CONTACT),
+            43 => Ok(mjtSensor::E_POTENTIAL),
+            44 => Ok(mjtSensor::E_KINETIC),
+            45 => Ok(mjtSensor::CLOCK),
+            46 => Ok(mjtSensor::TACTILE),
+            47 => Ok(mjtSensor::PLUGIN),
+            48 => Ok(mjtSensor::USER),
+
+            _ => Err(value),
+        }
+    }
+}
+impl TryFrom<i32> for mjtDataType {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(mjtDataType::REAL),
+            1 => Ok(mjtDataType::POSITIVE),
+            2 => Ok(mjtDataType::AXIS),
+            3 => Ok(mjtDataType::QUATERNION),
+
+            _ => Err(value),
+        }
+    }
+}
+impl TryFrom<i32> for mjtStage {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(mjtStage::NONE),
+            1 => Ok(mjtStage::POS),
+            2 => Ok(mjtStage::VEL),
+            3 => Ok(mjtStage::ACC),
+
+            _ => Err(value),
+        }
+    }
+}
@@ -10,6 +10,7 @@ bindgen $SCRIPT_DIR/binding.h -o $SCRIPT_DIR/src/lib.rs \
   --raw-line "#![allow(non_upper_case_globals)]" \
   --raw-line "#![allow(clippy::approx_constant)]" \
   --default-enum-style rust \
+  --override-abi ".*=C-unwind" \
   --generate=functions,types,vars \
   --allowlist-function="(mj).*" \
   --allowlist-type="(mj).*" \
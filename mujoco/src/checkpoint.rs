@@ -0,0 +1,195 @@
+//! Checkpoints of the full integration state, tied to the structure of a model
+//!
+//! With the `serde` feature enabled, [`Checkpoint`] can be serialized to disk
+//! and restored on another machine with [`Data::restore_checkpoint`].
+
+use crate::error::{Error, Result};
+use crate::{Data, Model, State, StateFlags};
+
+/// Summary of the model structure that a serialized state depends on
+///
+/// Two models with equal fingerprints have the same state layout and the same
+/// names, joint types and kinematic tree. Numeric parameters (masses, gains,
+/// ...) are not included, so tuned models can still resume a checkpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ModelFingerprint {
+    /// Number of bodies
+    pub nbody: usize,
+    /// Number of generalized coordinates
+    pub nq: usize,
+    /// Number of degrees of freedom
+    pub nv: usize,
+    /// Number of actuator activations
+    pub na: usize,
+    /// Number of actuators
+    pub nu: usize,
+    /// Number of equality constraints
+    pub neq: usize,
+    /// Number of mocap bodies
+    pub nmocap: usize,
+    /// Number of userdata values
+    pub nuserdata: usize,
+    /// Number of plugin state values
+    pub npluginstate: usize,
+    /// FNV-1a hash of names, joint types and body parents
+    pub structure: u64,
+}
+
+impl ModelFingerprint {
+    /// Compute the fingerprint of a model
+    pub fn new(model: &Model) -> Self {
+        // FNV-1a is stable across platforms and Rust versions, unlike std's DefaultHasher
+        let mut hash = 0xcbf2_9ce4_8422_2325u64;
+        let mut write = |bytes: &[u8]| {
+            for &byte in bytes {
+                hash = (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3);
+            }
+        };
+        let names =
+            unsafe { std::slice::from_raw_parts(model.raw().names as *const u8, model.nnames()) };
+        write(names);
+        for &joint_type in model.jnt_type() {
+            write(&(joint_type as i32).to_le_bytes());
+        }
+        for parent in model.body_parentid() {
+            write(&parent.to_le_bytes());
+        }
+
+        Self {
+            nbody: model.nbody(),
+            nq: model.nq(),
+            nv: model.nv(),
+            na: model.na(),
+            nu: model.nu(),
+            neq: model.neq(),
+            nmocap: model.nmocap(),
+            nuserdata: model.nuserdata(),
+            npluginstate: model.npluginstate(),
+            structure: hash,
+        }
+    }
+}
+
+/// Integration state of a simulation together with the fingerprint of its model
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Checkpoint {
+    fingerprint: ModelFingerprint,
+    state: State,
+}
+
+impl Checkpoint {
+    /// Fingerprint of the model the checkpoint was taken from
+    pub fn fingerprint(&self) -> &ModelFingerprint {
+        &self.fingerprint
+    }
+
+    /// Captured state
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+}
+
+impl Data<'_> {
+    /// Capture everything needed to continue the simulation exactly
+    ///
+    /// This covers time, qpos, qvel, act, history, warm-start accelerations,
+    /// controls, applied forces, mocap poses, equality activation, userdata and
+    /// plugin state.
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            fingerprint: ModelFingerprint::new(self.model()),
+            state: State::capture(self, StateFlags::INTEGRATION),
+        }
+    }
+
+    /// Restore a checkpoint, rejecting checkpoints taken from an incompatible model
+    pub fn restore_checkpoint(&mut self, checkpoint: &Checkpoint) -> Result<()> {
+        let fingerprint = ModelFingerprint::new(self.model());
+        if checkpoint.fingerprint != fingerprint {
+            return Err(Error::ModelMismatch(format!(
+                "checkpoint was taken from {:?}, this model is {:?}",
+                checkpoint.fingerprint, fingerprint
+            )));
+        }
+        checkpoint.state.restore(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_restore_checkpoint() {
+        let model = Model::from_file(crate::tests::test_xml_path()).unwrap();
+        let mut data = Data::new(&model);
+        data.qpos_mut()[1] = 0.5;
+        crate::step(&mut data).unwrap();
+        let checkpoint = data.checkpoint();
+
+        crate::step(&mut data).unwrap();
+        let stepped = data.qpos().to_vec();
+        let mut resumed = Data::new(&model);
+        resumed.restore_checkpoint(&checkpoint).unwrap();
+        crate::step(&mut resumed).unwrap();
+        assert_eq!(resumed.qpos(), stepped.as_slice());
+
+        // Same sizes, different structure
+        let mut spec = crate::Spec::from_str(crate::tests::test_xml_str()).unwrap();
+        crate::Element::set_name(&mut spec.body("link3").unwrap(), "forearm").unwrap();
+        let other = spec.compile().unwrap();
+        assert_eq!(other.nq(), model.nq());
+        let mut data = Data::new(&other);
+        assert!(matches!(
+            data.restore_checkpoint(&checkpoint),
+            Err(Error::ModelMismatch(_))
+        ));
+
+        // Extra equality constraint or body
+        let fingerprint = checkpoint.fingerprint();
+        let xml = crate::tests::test_xml_str().replace(
+            "</mujoco>",
+            r#"<equality><joint joint1="joint1" joint2="joint2"/></equality></mujoco>"#,
+        );
+        let other = crate::Spec::from_str(&xml).unwrap().compile().unwrap();
+        assert_eq!(ModelFingerprint::new(&other).neq, fingerprint.neq + 1);
+        let mut data = Data::new(&other);
+        assert!(matches!(
+            data.restore_checkpoint(&checkpoint),
+            Err(Error::ModelMismatch(_))
+        ));
+        let xml = crate::tests::test_xml_str().replace(
+            "</worldbody>",
+            r#"<body name="marker" pos="0 0 1"/></worldbody>"#,
+        );
+        let other = crate::Spec::from_str(&xml).unwrap().compile().unwrap();
+        assert_eq!(ModelFingerprint::new(&other).nbody, fingerprint.nbody + 1);
+        let mut data = Data::new(&other);
+        assert!(matches!(
+            data.restore_checkpoint(&checkpoint),
+            Err(Error::ModelMismatch(_))
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_checkpoint_serde() {
+        let model = Model::from_file(crate::tests::test_xml_path()).unwrap();
+        let mut data = Data::new(&model);
+        data.qvel_mut()[0] = 1.0;
+        crate::step(&mut data).unwrap();
+        let checkpoint = data.checkpoint();
+
+        let json = serde_json::to_string(&checkpoint).unwrap();
+        let loaded: Checkpoint = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded, checkpoint);
+
+        let mut resumed = Data::new(&model);
+        resumed.restore_checkpoint(&loaded).unwrap();
+        assert_eq!(resumed.qvel(), data.qvel());
+        assert_eq!(resumed.time(), data.time());
+    }
+}
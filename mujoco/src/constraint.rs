@@ -0,0 +1,171 @@
+//! Constraint Jacobian `efc_J` independent of the model's dense/sparse setting
+//!
+//! Depending on `mjOption::jacobian` (and `nv` when set to auto), MuJoCo stores
+//! `efc_J` either dense or in a compressed row format. The functions here accept
+//! both, and return the Jacobian computed by the last `mj_forward` (or
+//! `mj_fwdConstraint`). With the `sparse` feature enabled,
+//! [`constraint_jacobian_csr`] exports it as a `nalgebra_sparse::CsrMatrix`.
+
+use crate::error::{Error, Result};
+use crate::{DMatrix, DVector, Data};
+
+/// Whether `efc_J` of this data is stored in the sparse row format
+pub fn is_sparse(data: &Data) -> bool {
+    unsafe { mujoco_sys::mj_isSparse(data.model.as_ptr()) != 0 }
+}
+
+/// Dense `nefc`×`nv` constraint Jacobian
+pub fn constraint_jacobian(data: &Data) -> DMatrix<f64> {
+    let (nefc, nv) = (data.nefc(), data.model().nv());
+    if !is_sparse(data) {
+        return DMatrix::from_row_slice(nefc, nv, &data.efc_J()[..nefc * nv]);
+    }
+    let mut dense = vec![0.0; nefc * nv];
+    unsafe {
+        mujoco_sys::mju_sparse2dense(
+            dense.as_mut_ptr(),
+            data.efc_J().as_ptr(),
+            nefc as i32,
+            nv as i32,
+            data.efc_J_rownnz().as_ptr(),
+            data.efc_J_rowadr().as_ptr(),
+            data.efc_J_colind().as_ptr(),
+        )
+    };
+    DMatrix::from_row_slice(nefc, nv, &dense)
+}
+
+/// Sparse `nefc`×`nv` constraint Jacobian
+///
+/// Only structural nonzeros are stored: the sparse layout of `efc_J` is kept as is,
+/// and zeros are dropped when the model uses the dense layout.
+#[cfg(feature = "sparse")]
+pub fn constraint_jacobian_csr(data: &Data) -> nalgebra_sparse::CsrMatrix<f64> {
+    let (nefc, nv) = (data.nefc(), data.model().nv());
+    let mut row_offsets = Vec::with_capacity(nefc + 1);
+    let mut col_indices = Vec::new();
+    let mut values = Vec::new();
+    row_offsets.push(0);
+    if is_sparse(data) {
+        let (rownnz, rowadr) = (data.efc_J_rownnz(), data.efc_J_rowadr());
+        for row in 0..nefc {
+            let range = rowadr[row] as usize..(rowadr[row] + rownnz[row]) as usize;
+            col_indices.extend(
+                data.efc_J_colind()[range.clone()]
+                    .iter()
+                    .map(|&c| c as usize),
+            );
+            values.extend_from_slice(&data.efc_J()[range]);
+            row_offsets.push(values.len());
+        }
+    } else {
+        for row in data.efc_J()[..nefc * nv].chunks_exact(nv.max(1)) {
+            for (col, &value) in row.iter().enumerate().filter(|(_, v)| **v != 0.0) {
+                col_indices.push(col);
+                values.push(value);
+            }
+            row_offsets.push(values.len());
+        }
+    }
+    nalgebra_sparse::CsrMatrix::try_from_unsorted_csr_data(
+        nefc,
+        nv,
+        row_offsets,
+        col_indices,
+        values,
+    )
+    .expect("efc_J has a valid row structure")
+}
+
+fn check_len(vec: &DVector<f64>, expected: usize, name: &str) -> Result<()> {
+    if vec.len() != expected {
+        return Err(Error::InvalidBuffer(format!(
+            "vec has {} rows, expected {name} = {expected}",
+            vec.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Multiply a vector by the constraint Jacobian: `J * vec`, of size `nefc`
+///
+/// Returns [`Error::InvalidBuffer`] if `vec` does not have `nv` entries.
+pub fn mul_jac_vec(data: &Data, vec: &DVector<f64>) -> Result<DVector<f64>> {
+    check_len(vec, data.model().nv(), "nv")?;
+    let mut res = DVector::zeros(data.nefc());
+    crate::handlers::guard(|| unsafe {
+        mujoco_sys::mj_mulJacVec(
+            data.model.as_ptr(),
+            data.ptr,
+            res.as_mut_ptr(),
+            vec.as_ptr(),
+        )
+    })?;
+    Ok(res)
+}
+
+/// Multiply a vector by the transposed constraint Jacobian: `J' * vec`, of size `nv`
+///
+/// Returns [`Error::InvalidBuffer`] if `vec` does not have `nefc` entries.
+pub fn mul_jac_t_vec(data: &Data, vec: &DVector<f64>) -> Result<DVector<f64>> {
+    check_len(vec, data.nefc(), "nefc")?;
+    let mut res = DVector::zeros(data.model().nv());
+    crate::handlers::guard(|| unsafe {
+        mujoco_sys::mj_mulJacTVec(
+            data.model.as_ptr(),
+            data.ptr,
+            res.as_mut_ptr(),
+            vec.as_ptr(),
+        )
+    })?;
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    /// The test robot with its joints pushed past their limits, using the given Jacobian layout
+    fn limited(jacobian: &str) -> crate::Model {
+        let xml = crate::tests::test_xml_str().replace(
+            "<compiler",
+            &format!("<option jacobian=\"{jacobian}\" />\n  <compiler"),
+        );
+        crate::Spec::from_str(&xml).unwrap().compile().unwrap()
+    }
+
+    #[test]
+    fn test_constraint_jacobian_layouts() {
+        let dense_model = limited("dense");
+        let sparse_model = limited("sparse");
+        let mut jacobians = Vec::new();
+        for model in [&dense_model, &sparse_model] {
+            let mut data = Data::new(model);
+            data.qpos_mut().copy_from_slice(&[0.0, 3.3, -3.3]);
+            crate::forward(&mut data).unwrap();
+            // Two joint limits, plus any contacts between the folded links
+            assert!(data.nefc() >= 2);
+
+            let jac = constraint_jacobian(&data);
+            assert_eq!(jac.shape(), (data.nefc(), model.nv()));
+            let vec = DVector::from_vec(vec![0.5, -1.0, 2.0]);
+            assert!((mul_jac_vec(&data, &vec).unwrap() - &jac * &vec).amax() < 1e-12);
+            let force = DVector::from_fn(data.nefc(), |i, _| i as f64 + 1.0);
+            assert!(
+                (mul_jac_t_vec(&data, &force).unwrap() - jac.transpose() * &force).amax() < 1e-12
+            );
+            assert!(matches!(
+                mul_jac_vec(&data, &DVector::zeros(model.nv() + 1)),
+                Err(Error::InvalidBuffer(_))
+            ));
+
+            #[cfg(feature = "sparse")]
+            assert_eq!(DMatrix::from(&constraint_jacobian_csr(&data)), jac);
+            jacobians.push(jac);
+        }
+        assert!(!is_sparse(&Data::new(&dense_model)));
+        assert!(is_sparse(&Data::new(&sparse_model)));
+        assert_eq!(jacobians[0], jacobians[1]);
+    }
+}
@@ -0,0 +1,232 @@
+//! Typed view of the contacts detected by the last collision pass
+//!
+//! MuJoCo's contact frame has the normal as its first row (pointing from `geom1`
+//! to `geom2`) and the two tangent directions as the other rows. Contact forces are
+//! those exerted by `geom1` on `geom2`: a positive normal force pushes them apart.
+
+use crate::ids::optional;
+use crate::ids::sealed::Sealed;
+use crate::{BodyId, Data, GeomId, Mat3, Model, Vec3, Vec6};
+use mujoco_sys::mjContact;
+
+/// One contact of [`Data::contacts`]
+#[derive(Debug, Clone, Copy)]
+pub struct Contact<'d> {
+    model: &'d Model,
+    contact: &'d mjContact,
+    efc_force: &'d [f64],
+    id: usize,
+}
+
+impl Data<'_> {
+    /// Contacts detected by the last collision pass
+    pub fn contacts(&self) -> impl ExactSizeIterator<Item = Contact<'_>> {
+        let model = self.model();
+        let efc_force = self.efc_force();
+        self.contact()
+            .iter()
+            .enumerate()
+            .map(move |(id, contact)| Contact {
+                model,
+                contact,
+                efc_force,
+                id,
+            })
+    }
+}
+
+impl<'d> Contact<'d> {
+    /// Index of the contact in `Data::contact`
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// First geom, `None` for flex contacts
+    pub fn geom1(&self) -> Option<GeomId> {
+        optional(self.contact.geom[0])
+    }
+
+    /// Second geom, `None` for flex contacts
+    pub fn geom2(&self) -> Option<GeomId> {
+        optional(self.contact.geom[1])
+    }
+
+    /// Body of the first geom, `None` for flex contacts
+    pub fn body1(&self) -> Option<BodyId> {
+        self.geom1().map(|geom| self.body(geom))
+    }
+
+    /// Body of the second geom, `None` for flex contacts
+    pub fn body2(&self) -> Option<BodyId> {
+        self.geom2().map(|geom| self.body(geom))
+    }
+
+    fn body(&self, geom: GeomId) -> BodyId {
+        BodyId::from_index(self.model.geom_bodyid()[geom.index()] as usize)
+    }
+
+    /// Name of the first geom
+    pub fn geom1_name(&self) -> Option<&'d str> {
+        self.geom1()?.name(self.model)
+    }
+
+    /// Name of the second geom
+    pub fn geom2_name(&self) -> Option<&'d str> {
+        self.geom2()?.name(self.model)
+    }
+
+    /// Name of the body of the first geom
+    pub fn body1_name(&self) -> Option<&'d str> {
+        self.body1()?.name(self.model)
+    }
+
+    /// Name of the body of the second geom
+    pub fn body2_name(&self) -> Option<&'d str> {
+        self.body2()?.name(self.model)
+    }
+
+    /// Contact point, midway between the two surfaces
+    pub fn pos(&self) -> Vec3 {
+        Vec3::from_column_slice(&self.contact.pos)
+    }
+
+    /// Contact frame, rows are the normal and the two tangent directions
+    pub fn frame(&self) -> Mat3 {
+        Mat3::from_row_slice(&self.contact.frame)
+    }
+
+    /// Contact normal, pointing from `geom1` to `geom2`
+    pub fn normal(&self) -> Vec3 {
+        Vec3::from_column_slice(&self.contact.frame[..3])
+    }
+
+    /// Signed distance, negative for penetration
+    pub fn dist(&self) -> f64 {
+        self.contact.dist
+    }
+
+    /// Contact dimensionality (1, 3, 4 or 6)
+    pub fn dim(&self) -> usize {
+        self.contact.dim as usize
+    }
+
+    /// Whether the contact was excluded from the constraint solver
+    pub fn excluded(&self) -> bool {
+        self.contact.efc_address < 0
+    }
+
+    /// Force:torque in the contact frame (normal, then the two tangents)
+    ///
+    /// Pyramidal cones are decoded into normal and frictional components, so the
+    /// result is the same for both cone types.
+    pub fn local_force(&self) -> Vec6 {
+        let mut res = Vec6::zeros();
+        if self.excluded() {
+            return res;
+        }
+        let (adr, dim) = (self.contact.efc_address as usize, self.dim());
+        let efc_force = &self.efc_force[adr..];
+        if dim == 1 {
+            res[0] = efc_force[0];
+        } else if unsafe { mujoco_sys::mj_isPyramidal(self.model.as_ptr()) } != 0 {
+            unsafe {
+                mujoco_sys::mju_decodePyramid(
+                    res.as_mut_ptr(),
+                    efc_force.as_ptr(),
+                    self.contact.friction.as_ptr(),
+                    dim as i32,
+                )
+            };
+        } else {
+            res.as_mut_slice()[..dim].copy_from_slice(&efc_force[..dim]);
+        }
+        res
+    }
+
+    /// Force exerted by `geom1` on `geom2`, in world coordinates
+    pub fn force(&self) -> Vec3 {
+        self.frame().transpose() * self.local_force().fixed_rows::<3>(0)
+    }
+
+    /// Torsional and rolling friction torque exerted by `geom1` on `geom2`, in world coordinates
+    pub fn torque(&self) -> Vec3 {
+        self.frame().transpose() * self.local_force().fixed_rows::<3>(3)
+    }
+
+    /// Normal force magnitude, positive when the geoms are pushed apart
+    pub fn normal_force(&self) -> f64 {
+        self.local_force()[0]
+    }
+
+    /// Frictional part of [`Contact::force`], tangent to the contact surface
+    pub fn tangential_force(&self) -> Vec3 {
+        let local = self.local_force();
+        let frame = self.frame();
+        frame.row(1).transpose() * local[1] + frame.row(2).transpose() * local[2]
+    }
+
+    /// Underlying MuJoCo contact
+    pub fn raw(&self) -> &'d mjContact {
+        self.contact
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn ball_on_floor(cone: &str) -> Model {
+        let xml = format!(
+            r#"<mujoco>
+              <option cone="{cone}" />
+              <worldbody>
+                <geom name="floor" type="plane" size="1 1 0.1" />
+                <body name="ball" pos="0.1 0.2 0.1">
+                  <freejoint />
+                  <geom name="sphere" type="sphere" size="0.1" mass="2" />
+                </body>
+              </worldbody>
+            </mujoco>"#
+        );
+        crate::Spec::from_str(&xml).unwrap().compile().unwrap()
+    }
+
+    #[test]
+    fn test_contact_view() {
+        for cone in ["pyramidal", "elliptic"] {
+            let model = ball_on_floor(cone);
+            let mut data = Data::new(&model);
+            data.qvel_mut()[0] = 0.5;
+            for _ in 0..500 {
+                crate::step(&mut data).unwrap();
+            }
+            crate::forward(&mut data).unwrap();
+            assert_eq!(data.contacts().len(), 1);
+            let contact = data.contacts().next().unwrap();
+
+            assert_eq!(contact.geom1_name(), Some("floor"));
+            assert_eq!(contact.geom2_name(), Some("sphere"));
+            assert_eq!(contact.body1(), Some(BodyId::WORLD));
+            assert_eq!(contact.body2_name(), Some("ball"));
+            assert!((contact.normal() - Vec3::z()).norm() < 1e-9);
+            assert!(contact.pos().z.abs() < 1e-2);
+            assert!(contact.dist() <= 0.0);
+
+            // Decoding matches mj_contactForce, and the floor carries the ball
+            assert_eq!(
+                contact.local_force(),
+                crate::contact_force(&data, 0).unwrap()
+            );
+            assert!(matches!(
+                crate::contact_force(&data, 1),
+                Err(crate::Error::InvalidBuffer(_))
+            ));
+            let weight = 2.0 * -model.opt().gravity[2];
+            assert!((contact.force().z - weight).abs() < 0.05 * weight);
+            assert!((contact.normal_force() - contact.force().z).abs() < 1e-9);
+            let split = contact.normal() * contact.normal_force() + contact.tangential_force();
+            assert!((split - contact.force()).norm() < 1e-9);
+        }
+    }
+}
@@ -1,7 +1,33 @@
+use std::sync::Arc;
+
+/// Simulation data for a [`Model`](crate::Model)
+///
+/// The model is either borrowed (`Data<'a>` cannot outlive it) or shared through an
+/// [`Arc`], in which case the data is `Data<'static>` and can be stored in long-lived
+/// structs or moved across threads together with its model.
 #[derive(Debug)]
 pub struct Data<'a> {
     pub(crate) ptr: *mut mujoco_sys::mjData,
-    pub(crate) model: &'a crate::Model,
+    pub(crate) model: ModelRef<'a>,
+    pub(crate) thread_pool: Option<crate::ThreadPool>,
+}
+
+/// Model referenced by a [`Data`]
+#[derive(Debug, Clone)]
+pub(crate) enum ModelRef<'a> {
+    Borrowed(&'a crate::Model),
+    Shared(Arc<crate::Model>),
+}
+
+impl std::ops::Deref for ModelRef<'_> {
+    type Target = crate::Model;
+
+    fn deref(&self) -> &crate::Model {
+        match self {
+            ModelRef::Borrowed(model) => model,
+            ModelRef::Shared(model) => model,
+        }
+    }
 }
 
 unsafe impl Send for Data<'_> {}
@@ -9,9 +35,52 @@ unsafe impl Sync for Data<'_> {}
 
 impl<'a> Data<'a> {
     pub fn new(model: &'a crate::Model) -> Self {
-        let ptr = unsafe { mujoco_sys::mj_makeData(model.as_ptr()) };
-        assert_ne!(ptr, std::ptr::null_mut());
-        Self { ptr, model }
+        Self::try_new(model).expect("mj_makeData failed")
+    }
+
+    /// Allocate data for the model, returning an error instead of panicking on failure
+    pub fn try_new(model: &'a crate::Model) -> crate::Result<Self> {
+        Self::make(ModelRef::Borrowed(model))
+    }
+
+    fn make(model: ModelRef<'a>) -> crate::Result<Self> {
+        let ptr = crate::handlers::guard(|| unsafe { mujoco_sys::mj_makeData(model.as_ptr()) })?;
+        if ptr.is_null() {
+            return Err(crate::Error::Allocation("mjData"));
+        }
+        Ok(Self {
+            ptr,
+            model,
+            thread_pool: None,
+        })
+    }
+
+    /// Model this data belongs to
+    pub fn model(&self) -> &crate::Model {
+        &self.model
+    }
+
+    /// Shared model this data keeps alive, if it was created with [`Data::new_shared`]
+    pub fn shared_model(&self) -> Option<&Arc<crate::Model>> {
+        match &self.model {
+            ModelRef::Borrowed(_) => None,
+            ModelRef::Shared(model) => Some(model),
+        }
+    }
+
+    /// Copy the data, returning an error instead of panicking if allocation fails
+    pub fn try_clone(&self) -> crate::Result<Self> {
+        let ptr = crate::handlers::guard(|| unsafe {
+            mujoco_sys::mj_copyData(std::ptr::null_mut(), self.model.as_ptr(), self.ptr)
+        })?;
+        if ptr.is_null() {
+            return Err(crate::Error::Allocation("mjData"));
+        }
+        Ok(Self {
+            ptr,
+            model: self.model.clone(),
+            thread_pool: self.thread_pool.clone(),
+        })
     }
 
     /// Get the raw pointer (for FFI calls)
@@ -24,6 +93,34 @@ impl<'a> Data<'a> {
         self.ptr
     }
 
+    /// Release the borrow of the model while keeping the simulation state
+    ///
+    /// This allows the model to be mutated, e.g. by [`crate::Spec::recompile`].
+    /// Data created with [`Data::new_shared`] keeps its reference to the model, which
+    /// is handed back by [`DetachedData::attach`].
+    pub fn detach(self) -> DetachedData {
+        let mut data = std::mem::ManuallyDrop::new(self);
+        // Move the model reference out; the mjData itself is handed over below
+        let model = unsafe { std::ptr::read(&data.model) };
+        DetachedData {
+            ptr: data.ptr,
+            model_id: model.id,
+            owner: match model {
+                ModelRef::Borrowed(_) => None,
+                ModelRef::Shared(model) => Some(model),
+            },
+            thread_pool: data.thread_pool.take(),
+        }
+    }
+
+    /// Reset the data to the keyframe `key`, given its name or id
+    pub fn reset_to_keyframe(
+        &mut self,
+        key: impl crate::IntoId<crate::KeyframeId>,
+    ) -> crate::Result<()> {
+        crate::reset_data_keyframe(self, key)
+    }
+
     /// Get immutable reference to the raw model
     pub fn raw(&self) -> &mujoco_sys::mjData {
         unsafe { &*self.ptr }
@@ -35,6 +132,18 @@ impl<'a> Data<'a> {
     }
 }
 
+impl Data<'static> {
+    /// Allocate data that owns a reference to its model
+    pub fn new_shared(model: Arc<crate::Model>) -> Self {
+        Self::try_new_shared(model).expect("mj_makeData failed")
+    }
+
+    /// Like [`Data::new_shared`], returning an error instead of panicking on failure
+    pub fn try_new_shared(model: Arc<crate::Model>) -> crate::Result<Self> {
+        Self::make(ModelRef::Shared(model))
+    }
+}
+
 impl Drop for Data<'_> {
     fn drop(&mut self) {
         unsafe { mujoco_sys::mj_deleteData(self.ptr) }
@@ -43,13 +152,88 @@ impl Drop for Data<'_> {
 
 impl Clone for Data<'_> {
     fn clone(&self) -> Self {
-        let ptr =
-            unsafe { mujoco_sys::mj_copyData(std::ptr::null_mut(), self.model.as_ptr(), self.ptr) };
-        assert_ne!(ptr, std::ptr::null_mut());
-        Self {
-            ptr,
-            model: self.model,
+        self.try_clone().expect("mj_copyData failed")
+    }
+}
+
+/// Simulation data that does not borrow the model it was created for
+///
+/// Obtained from [`Data::detach`]; call [`DetachedData::attach`] to access the
+/// state again.
+#[derive(Debug)]
+pub struct DetachedData {
+    pub(crate) ptr: *mut mujoco_sys::mjData,
+    model_id: u64,
+    /// Model kept alive for data detached from a [`Data::new_shared`]
+    owner: Option<Arc<crate::Model>>,
+    thread_pool: Option<crate::ThreadPool>,
+}
+
+unsafe impl Send for DetachedData {}
+unsafe impl Sync for DetachedData {}
+
+impl DetachedData {
+    /// Attach the data to the model it was detached from
+    ///
+    /// Data detached from a shared model gets its shared reference back.
+    /// Returns [`crate::Error::ModelMismatch`] if `model` is not the model this data
+    /// was created for; the data is dropped in that case.
+    pub fn attach(self, model: &crate::Model) -> crate::Result<Data<'_>> {
+        self.check_model(model)?;
+        let mut data = std::mem::ManuallyDrop::new(self);
+        let model = match data.owner.take() {
+            Some(owner) => ModelRef::Shared(owner),
+            None => ModelRef::Borrowed(model),
+        };
+        Ok(Data {
+            ptr: data.ptr,
+            model,
+            thread_pool: data.thread_pool.take(),
+        })
+    }
+
+    /// Attach the data to the shared model it was detached from
+    ///
+    /// Returns [`crate::Error::ModelMismatch`] if the data was created for a borrowed
+    /// model; the data is dropped in that case.
+    pub fn attach_shared(mut self) -> crate::Result<Data<'static>> {
+        let Some(owner) = self.owner.take() else {
+            return Err(crate::Error::ModelMismatch(
+                "data was detached from a borrowed model".into(),
+            ));
+        };
+        let mut data = std::mem::ManuallyDrop::new(self);
+        Ok(Data {
+            ptr: data.ptr,
+            model: ModelRef::Shared(owner),
+            thread_pool: data.thread_pool.take(),
+        })
+    }
+
+    /// Shared model this data keeps alive, if it was detached from a shared `Data`
+    pub fn shared_model(&self) -> Option<&Arc<crate::Model>> {
+        self.owner.as_ref()
+    }
+
+    /// Check that the data was created for `model`
+    pub(crate) fn check_model(&self, model: &crate::Model) -> crate::Result<()> {
+        if self.model_id != model.id {
+            return Err(crate::Error::ModelMismatch(
+                "data was created for a different model".into(),
+            ));
         }
+        Ok(())
+    }
+
+    /// Mark the data as matching `model`, after MuJoCo resized it for a new layout
+    pub(crate) fn rebind(&mut self, model: &crate::Model) {
+        self.model_id = model.id;
+    }
+}
+
+impl Drop for DetachedData {
+    fn drop(&mut self) {
+        unsafe { mujoco_sys::mj_deleteData(self.ptr) }
     }
 }
 
@@ -62,17 +246,61 @@ mod tests {
         let model = crate::Model::from_file(crate::tests::test_xml_path()).unwrap();
         let mut data = Data::new(&model);
         assert_ne!(data.ptr, std::ptr::null_mut());
-        crate::forward(&mut data);
-        crate::step(&mut data);
+        crate::forward(&mut data).unwrap();
+        crate::step(&mut data).unwrap();
         data.qpos_mut()[0] += 0.1;
-        crate::forward(&mut data);
-        crate::step(&mut data);
+        crate::forward(&mut data).unwrap();
+        crate::step(&mut data).unwrap();
         let mut data2 = data.clone();
         assert_eq!(data2.qpos(), data.qpos());
         data2.qpos_mut()[0] += 0.1;
         assert_ne!(data2.qpos(), data.qpos());
     }
 
+    #[test]
+    fn shared_model() {
+        let model = Arc::new(crate::Model::from_file(crate::tests::test_xml_path()).unwrap());
+        let mut data = Data::new_shared(model.clone());
+        data.qpos_mut()[0] = 0.5;
+
+        // Data<'static> can be moved to another thread together with its model
+        let handle = std::thread::spawn(move || {
+            crate::step(&mut data).unwrap();
+            data
+        });
+        let data = handle.join().unwrap();
+        assert_eq!(data.qpos()[0], 0.5);
+        assert!(Arc::ptr_eq(data.shared_model().unwrap(), &model));
+
+        let copy = data.clone();
+        assert_eq!(Arc::strong_count(&model), 3);
+        drop(copy);
+        // Detached data keeps the model alive and hands it back on attach
+        let detached = data.detach();
+        assert_eq!(Arc::strong_count(&model), 2);
+        drop(model);
+        let model = detached.shared_model().unwrap().clone();
+        let data = detached.attach(&model).unwrap();
+        assert!(Arc::ptr_eq(data.shared_model().unwrap(), &model));
+        let data = data.detach().attach_shared().unwrap();
+        assert_eq!(data.qpos()[0], 0.5);
+
+        let borrowed = crate::Model::from_file(crate::tests::test_xml_path()).unwrap();
+        assert!(Data::new(&borrowed).detach().attach_shared().is_err());
+    }
+
+    #[test]
+    fn attach_checks_model() {
+        let model = crate::Model::from_file(crate::tests::test_xml_path()).unwrap();
+        let copy = model.clone();
+        let detached = Data::new(&model).detach();
+        assert!(matches!(
+            detached.attach(&copy),
+            Err(crate::Error::ModelMismatch(_))
+        ));
+        assert!(Data::new(&model).detach().attach(&model).is_ok());
+    }
+
     /// Verify the Jacobian wrapper produces correct element values by comparing
     /// against raw FFI output. MuJoCo writes row-major 3×nv matrices; nalgebra
     /// stores column-major. This test catches any layout mismatch.
@@ -85,13 +313,13 @@ mod tests {
         data.qpos_mut()[0] = 0.5;
         data.qpos_mut()[1] = 0.3;
         data.qpos_mut()[2] = -0.2;
-        crate::forward(&mut data);
+        crate::forward(&mut data).unwrap();
 
         let nv = model.nv();
-        let body = 4; // end_effector (base_link=0, link1=1, link2=2, link3=3, end_effector=4)
+        let body = crate::BodyId::from_name(&model, "link3").unwrap();
 
         // Get Jacobian via our wrapper
-        let jac = crate::jac_body(&data, body);
+        let jac = crate::jac_body(&data, body).unwrap();
 
         // Get Jacobian via raw FFI into separate flat buffers
         let mut jacp_raw = vec![0.0f64; 3 * nv];
@@ -102,7 +330,7 @@ mod tests {
                 data.as_ptr(),
                 jacp_raw.as_mut_ptr(),
                 jacr_raw.as_mut_ptr(),
-                body,
+                body.index() as i32,
             );
         }
 
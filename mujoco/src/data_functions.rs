@@ -4,311 +4,311 @@
 use crate::Data;
 
 /// Advance simulation, use control callback to obtain external force and control.
-pub fn step(data: &mut Data) {
-    unsafe {
+pub fn step(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_step(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Advance simulation in two steps: before external force and control is set by user.
-pub fn step1(data: &mut Data) {
-    unsafe {
+pub fn step1(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_step1(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Advance simulation in two steps: after external force and control is set by user.
-pub fn step2(data: &mut Data) {
-    unsafe {
+pub fn step2(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_step2(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Forward dynamics: same as mj_step but do not integrate in time.
-pub fn forward(data: &mut Data) {
-    unsafe {
+pub fn forward(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_forward(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Inverse dynamics: qacc must be set before calling.
-pub fn inverse(data: &mut Data) {
-    unsafe {
+pub fn inverse(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_inverse(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Reset data to defaults.
-pub fn reset_data(data: &mut Data) {
-    unsafe {
+pub fn reset_data(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_resetData(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Run all kinematics-like computations (kinematics, comPos, camlight, flex, tendon).
-pub fn fwd_kinematics(data: &mut Data) {
-    unsafe {
+pub fn fwd_kinematics(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_fwdKinematics(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Run position-dependent computations.
-pub fn fwd_position(data: &mut Data) {
-    unsafe {
+pub fn fwd_position(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_fwdPosition(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Run velocity-dependent computations.
-pub fn fwd_velocity(data: &mut Data) {
-    unsafe {
+pub fn fwd_velocity(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_fwdVelocity(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Compute actuator force qfrc_actuator.
-pub fn fwd_actuation(data: &mut Data) {
-    unsafe {
+pub fn fwd_actuation(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_fwdActuation(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Add up all non-constraint forces, compute qacc_smooth.
-pub fn fwd_acceleration(data: &mut Data) {
-    unsafe {
+pub fn fwd_acceleration(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_fwdAcceleration(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Run selected constraint solver.
-pub fn fwd_constraint(data: &mut Data) {
-    unsafe {
+pub fn fwd_constraint(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_fwdConstraint(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Euler integrator, semi-implicit in velocity.
-pub fn euler(data: &mut Data) {
-    unsafe {
+pub fn euler(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_Euler(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Implicit-in-velocity integrators.
-pub fn implicit(data: &mut Data) {
-    unsafe {
+pub fn implicit(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_implicit(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Run position-dependent computations in inverse dynamics.
-pub fn inv_position(data: &mut Data) {
-    unsafe {
+pub fn inv_position(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_invPosition(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Run velocity-dependent computations in inverse dynamics.
-pub fn inv_velocity(data: &mut Data) {
-    unsafe {
+pub fn inv_velocity(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_invVelocity(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Apply the analytical formula for inverse constraint dynamics.
-pub fn inv_constraint(data: &mut Data) {
-    unsafe {
+pub fn inv_constraint(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_invConstraint(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Compare forward and inverse dynamics, save results in fwdinv.
-pub fn compare_fwd_inv(data: &mut Data) {
-    unsafe {
+pub fn compare_fwd_inv(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_compareFwdInv(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Evaluate position-dependent sensors.
-pub fn sensor_pos(data: &mut Data) {
-    unsafe {
+pub fn sensor_pos(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_sensorPos(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Evaluate velocity-dependent sensors.
-pub fn sensor_vel(data: &mut Data) {
-    unsafe {
+pub fn sensor_vel(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_sensorVel(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Evaluate acceleration and force-dependent sensors.
-pub fn sensor_acc(data: &mut Data) {
-    unsafe {
+pub fn sensor_acc(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_sensorAcc(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Evaluate position-dependent energy (potential).
-pub fn energy_pos(data: &mut Data) {
-    unsafe {
+pub fn energy_pos(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_energyPos(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Evaluate velocity-dependent energy (kinetic).
-pub fn energy_vel(data: &mut Data) {
-    unsafe {
+pub fn energy_vel(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_energyVel(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Check qpos, reset if any element is too big or nan.
-pub fn check_pos(data: &mut Data) {
-    unsafe {
+pub fn check_pos(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_checkPos(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Check qvel, reset if any element is too big or nan.
-pub fn check_vel(data: &mut Data) {
-    unsafe {
+pub fn check_vel(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_checkVel(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Check qacc, reset if any element is too big or nan.
-pub fn check_acc(data: &mut Data) {
-    unsafe {
+pub fn check_acc(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_checkAcc(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Run forward kinematics.
-pub fn kinematics(data: &mut Data) {
-    unsafe {
+pub fn kinematics(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_kinematics(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Map inertias and motion dofs to global frame centered at CoM.
-pub fn com_pos(data: &mut Data) {
-    unsafe {
+pub fn com_pos(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_comPos(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Compute camera and light positions and orientations.
-pub fn camlight(data: &mut Data) {
-    unsafe {
+pub fn camlight(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_camlight(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Compute flex-related quantities.
-pub fn flex(data: &mut Data) {
-    unsafe {
+pub fn flex(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_flex(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Compute tendon lengths, velocities and moment arms.
-pub fn tendon(data: &mut Data) {
-    unsafe {
+pub fn tendon(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_tendon(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Compute actuator transmission lengths and moments.
-pub fn transmission(data: &mut Data) {
-    unsafe {
+pub fn transmission(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_transmission(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Run composite rigid body inertia algorithm (CRB).
-pub fn crb(data: &mut Data) {
-    unsafe {
+pub fn crb(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_crb(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Make inertia matrix.
-pub fn make_m(data: &mut Data) {
-    unsafe {
+pub fn make_m(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_makeM(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Compute sparse L'*D*L factorizaton of inertia matrix.
-pub fn factor_m(data: &mut Data) {
-    unsafe {
+pub fn factor_m(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_factorM(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Compute cvel, cdof_dot.
-pub fn com_vel(data: &mut Data) {
-    unsafe {
+pub fn com_vel(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_comVel(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Compute qfrc_passive from spring-dampers, gravity compensation and fluid forces.
-pub fn passive(data: &mut Data) {
-    unsafe {
+pub fn passive(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_passive(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Sub-tree linear velocity and angular momentum: compute subtree_linvel, subtree_angmom.
-pub fn subtree_vel(data: &mut Data) {
-    unsafe {
+pub fn subtree_vel(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_subtreeVel(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// RNE with complete data: compute cacc, cfrc_ext, cfrc_int.
-pub fn rne_post_constraint(data: &mut Data) {
-    unsafe {
+pub fn rne_post_constraint(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_rnePostConstraint(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Run collision detection.
-pub fn collision(data: &mut Data) {
-    unsafe {
+pub fn collision(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_collision(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Construct constraints.
-pub fn make_constraint(data: &mut Data) {
-    unsafe {
+pub fn make_constraint(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_makeConstraint(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Find constraint islands.
-pub fn island(data: &mut Data) {
-    unsafe {
+pub fn island(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_island(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Compute inverse constraint inertia efc_AR.
-pub fn project_constraint(data: &mut Data) {
-    unsafe {
+pub fn project_constraint(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_projectConstraint(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Compute efc_vel, efc_aref.
-pub fn reference_constraint(data: &mut Data) {
-    unsafe {
+pub fn reference_constraint(data: &mut Data) -> crate::Result<()> {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_referenceConstraint(data.model.as_ptr(), data.as_mut_ptr());
-    }
+    })
 }
 
 /// Return version number: 1.0.2 is encoded as 102.
@@ -326,8 +326,10 @@ pub fn version_string() -> &'static str {
 }
 
 /// Get id of object with the specified mjtObj type and name; return -1 if id not found.
+/// Covers every object type, including those without a typed id (meshes, materials, ...);
+/// prefer the typed lookups such as [`crate::BodyId::from_name`] where one exists.
 pub fn name2id(model: &crate::Model, obj_type: mujoco_sys::mjtObj, name: &str) -> Option<i32> {
-    let name_cstr = std::ffi::CString::new(name).unwrap();
+    let name_cstr = std::ffi::CString::new(name).ok()?;
     let id = unsafe { mujoco_sys::mj_name2id(model.as_ptr(), obj_type as i32, name_cstr.as_ptr()) };
     if id >= 0 { Some(id) } else { None }
 }
@@ -345,8 +347,14 @@ pub fn id2name(model: &crate::Model, obj_type: mujoco_sys::mjtObj, id: i32) -> O
 }
 
 /// Reset data. If 0 <= key < nkey, set fields from specified keyframe.
-pub fn reset_data_keyframe(data: &mut crate::Data, key: i32) {
-    unsafe { mujoco_sys::mj_resetDataKeyframe(data.model.as_ptr(), data.as_mut_ptr(), key) }
+pub fn reset_data_keyframe(
+    data: &mut crate::Data,
+    key: impl crate::IntoId<crate::KeyframeId>,
+) -> crate::Result<()> {
+    let key = key.into_id(data.model())?;
+    crate::handlers::guard(|| unsafe {
+        mujoco_sys::mj_resetDataKeyframe(data.model.as_ptr(), data.as_mut_ptr(), key.index() as i32)
+    })
 }
 
 /// Compute velocity by finite-differencing two positions.
@@ -381,12 +389,12 @@ pub fn normalize_quat(model: &crate::Model, qpos: &mut [f64]) {
 /// Compute object 6D velocity (rot:lin) in object-centered frame, world/local orientation.
 pub fn object_velocity(
     data: &crate::Data,
-    objtype: mujoco_sys::mjtObj,
-    objid: i32,
+    object: impl Into<crate::Object>,
     flg_local: bool,
-) -> crate::Vec6 {
+) -> crate::Result<crate::Vec6> {
+    let (objtype, objid) = object.into().checked(data.model())?;
     let mut res = crate::Vec6::zeros();
-    unsafe {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_objectVelocity(
             data.model.as_ptr(),
             data.as_ptr(),
@@ -395,19 +403,19 @@ pub fn object_velocity(
             res.as_mut_ptr(),
             flg_local as i32,
         )
-    }
-    res
+    })?;
+    Ok(res)
 }
 
 /// Compute object 6D acceleration (rot:lin) in object-centered frame, world/local orientation.
 pub fn object_acceleration(
     data: &crate::Data,
-    objtype: mujoco_sys::mjtObj,
-    objid: i32,
+    object: impl Into<crate::Object>,
     flg_local: bool,
-) -> crate::Vec6 {
+) -> crate::Result<crate::Vec6> {
+    let (objtype, objid) = object.into().checked(data.model())?;
     let mut res = crate::Vec6::zeros();
-    unsafe {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_objectAcceleration(
             data.model.as_ptr(),
             data.as_ptr(),
@@ -416,50 +424,65 @@ pub fn object_acceleration(
             res.as_mut_ptr(),
             flg_local as i32,
         )
-    }
-    res
+    })?;
+    Ok(res)
 }
 
 /// Extract 6D force:torque given contact id, in the contact frame.
-pub fn contact_force(data: &crate::Data, id: i32) -> crate::Vec6 {
+/// Returns [`crate::Error::InvalidBuffer`] if `id` is not smaller than `data.ncon()`.
+pub fn contact_force(data: &crate::Data, id: usize) -> crate::Result<crate::Vec6> {
+    if id >= data.ncon() {
+        return Err(crate::Error::InvalidBuffer(format!(
+            "contact index {id} out of bounds (ncon = {})",
+            data.ncon()
+        )));
+    }
     let mut res = crate::Vec6::zeros();
-    unsafe { mujoco_sys::mj_contactForce(data.model.as_ptr(), data.as_ptr(), id, res.as_mut_ptr()) }
-    res
+    crate::handlers::guard(|| unsafe {
+        mujoco_sys::mj_contactForce(
+            data.model.as_ptr(),
+            data.as_ptr(),
+            id as i32,
+            res.as_mut_ptr(),
+        )
+    })?;
+    Ok(res)
 }
 
 /// Return smallest signed distance between two geoms and optionally segment from geom1 to geom2.
 pub fn geom_distance(
     data: &crate::Data,
-    geom1: i32,
-    geom2: i32,
+    geom1: impl crate::IntoId<crate::GeomId>,
+    geom2: impl crate::IntoId<crate::GeomId>,
     distmax: f64,
-) -> (f64, crate::Vec6) {
+) -> crate::Result<(f64, crate::Vec6)> {
+    let geom1 = geom1.into_id(data.model())?;
+    let geom2 = geom2.into_id(data.model())?;
     let mut fromto = crate::Vec6::zeros();
-    let dist = unsafe {
+    let dist = crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_geomDistance(
             data.model.as_ptr(),
             data.as_ptr(),
-            geom1,
-            geom2,
+            geom1.index() as i32,
+            geom2.index() as i32,
             distmax,
             fromto.as_mut_ptr(),
         )
-    };
-    (dist, fromto)
+    })?;
+    Ok((dist, fromto))
 }
 
 /// Compute 3/6-by-nv end-effector Jacobian of global point attached to given body.
 /// Returns a 6×nv Jacobian matrix (top 3 rows: position, bottom 3 rows: rotation).
-pub fn jac(data: &crate::Data, point: &crate::Vec3, body: i32) -> crate::Jacobian6xN {
-    debug_assert!(
-        (body as usize) < data.model.nbody(),
-        "body index {} out of bounds (nbody = {})",
-        body,
-        data.model.nbody()
-    );
+pub fn jac(
+    data: &crate::Data,
+    point: &crate::Vec3,
+    body: impl crate::IntoId<crate::BodyId>,
+) -> crate::Result<crate::Jacobian6xN> {
+    let body = body.into_id(data.model())?;
     let nv = data.model.nv();
     let mut jac_t = crate::JacobianNx6::zeros(nv);
-    unsafe {
+    crate::handlers::guard(|| unsafe {
         let ptr = jac_t.as_mut_ptr();
         mujoco_sys::mj_jac(
             data.model.as_ptr(),
@@ -467,128 +490,118 @@ pub fn jac(data: &crate::Data, point: &crate::Vec3, body: i32) -> crate::Jacobia
             ptr,
             ptr.add(3 * nv),
             point.as_ptr(),
-            body,
+            body.index() as i32,
         );
-    }
-    jac_t.transpose()
+    })?;
+    Ok(jac_t.transpose())
 }
 
 /// Compute body frame end-effector Jacobian.
 /// Returns a 6×nv Jacobian matrix (top 3 rows: position, bottom 3 rows: rotation).
-pub fn jac_body(data: &crate::Data, body: i32) -> crate::Jacobian6xN {
-    debug_assert!(
-        (body as usize) < data.model.nbody(),
-        "body index {} out of bounds (nbody = {})",
-        body,
-        data.model.nbody()
-    );
+pub fn jac_body(
+    data: &crate::Data,
+    body: impl crate::IntoId<crate::BodyId>,
+) -> crate::Result<crate::Jacobian6xN> {
+    let body = body.into_id(data.model())?;
     let nv = data.model.nv();
     let mut jac_t = crate::JacobianNx6::zeros(nv);
-    unsafe {
+    crate::handlers::guard(|| unsafe {
         let ptr = jac_t.as_mut_ptr();
         mujoco_sys::mj_jacBody(
             data.model.as_ptr(),
             data.as_ptr(),
             ptr,
             ptr.add(3 * nv),
-            body,
+            body.index() as i32,
         );
-    }
-    jac_t.transpose()
+    })?;
+    Ok(jac_t.transpose())
 }
 
 /// Compute body center-of-mass end-effector Jacobian.
 /// Returns a 6×nv Jacobian matrix (top 3 rows: position, bottom 3 rows: rotation).
-pub fn jac_body_com(data: &crate::Data, body: i32) -> crate::Jacobian6xN {
-    debug_assert!(
-        (body as usize) < data.model.nbody(),
-        "body index {} out of bounds (nbody = {})",
-        body,
-        data.model.nbody()
-    );
+pub fn jac_body_com(
+    data: &crate::Data,
+    body: impl crate::IntoId<crate::BodyId>,
+) -> crate::Result<crate::Jacobian6xN> {
+    let body = body.into_id(data.model())?;
     let nv = data.model.nv();
     let mut jac_t = crate::JacobianNx6::zeros(nv);
-    unsafe {
+    crate::handlers::guard(|| unsafe {
         let ptr = jac_t.as_mut_ptr();
         mujoco_sys::mj_jacBodyCom(
             data.model.as_ptr(),
             data.as_ptr(),
             ptr,
             ptr.add(3 * nv),
-            body,
+            body.index() as i32,
         );
-    }
-    jac_t.transpose()
+    })?;
+    Ok(jac_t.transpose())
 }
 
 /// Compute subtree center-of-mass end-effector Jacobian.
 /// Returns a 3×nv position Jacobian matrix.
-pub fn jac_subtree_com(data: &mut crate::Data, body: i32) -> crate::Jacobian3xN {
-    debug_assert!(
-        (body as usize) < data.model.nbody(),
-        "body index {} out of bounds (nbody = {})",
-        body,
-        data.model.nbody()
-    );
+pub fn jac_subtree_com(
+    data: &mut crate::Data,
+    body: impl crate::IntoId<crate::BodyId>,
+) -> crate::Result<crate::Jacobian3xN> {
+    let body = body.into_id(data.model())?;
     let nv = data.model.nv();
     let mut jac_t = crate::JacobianNx3::zeros(nv);
-    unsafe {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_jacSubtreeCom(
             data.model.as_ptr(),
             data.as_mut_ptr(),
             jac_t.as_mut_ptr(),
-            body,
+            body.index() as i32,
         );
-    }
-    jac_t.transpose()
+    })?;
+    Ok(jac_t.transpose())
 }
 
 /// Compute geom end-effector Jacobian.
 /// Returns a 6×nv Jacobian matrix (top 3 rows: position, bottom 3 rows: rotation).
-pub fn jac_geom(data: &crate::Data, geom: i32) -> crate::Jacobian6xN {
-    debug_assert!(
-        (geom as usize) < data.model.ngeom(),
-        "geom index {} out of bounds (ngeom = {})",
-        geom,
-        data.model.ngeom()
-    );
+pub fn jac_geom(
+    data: &crate::Data,
+    geom: impl crate::IntoId<crate::GeomId>,
+) -> crate::Result<crate::Jacobian6xN> {
+    let geom = geom.into_id(data.model())?;
     let nv = data.model.nv();
     let mut jac_t = crate::JacobianNx6::zeros(nv);
-    unsafe {
+    crate::handlers::guard(|| unsafe {
         let ptr = jac_t.as_mut_ptr();
         mujoco_sys::mj_jacGeom(
             data.model.as_ptr(),
             data.as_ptr(),
             ptr,
             ptr.add(3 * nv),
-            geom,
+            geom.index() as i32,
         );
-    }
-    jac_t.transpose()
+    })?;
+    Ok(jac_t.transpose())
 }
 
 /// Compute site end-effector Jacobian.
 /// Returns a 6×nv Jacobian matrix (top 3 rows: position, bottom 3 rows: rotation).
-pub fn jac_site(data: &crate::Data, site: i32) -> crate::Jacobian6xN {
-    debug_assert!(
-        (site as usize) < data.model.nsite(),
-        "site index {} out of bounds (nsite = {})",
-        site,
-        data.model.nsite()
-    );
+pub fn jac_site(
+    data: &crate::Data,
+    site: impl crate::IntoId<crate::SiteId>,
+) -> crate::Result<crate::Jacobian6xN> {
+    let site = site.into_id(data.model())?;
     let nv = data.model.nv();
     let mut jac_t = crate::JacobianNx6::zeros(nv);
-    unsafe {
+    crate::handlers::guard(|| unsafe {
         let ptr = jac_t.as_mut_ptr();
         mujoco_sys::mj_jacSite(
             data.model.as_ptr(),
             data.as_ptr(),
             ptr,
             ptr.add(3 * nv),
-            site,
+            site.index() as i32,
         );
-    }
-    jac_t.transpose()
+    })?;
+    Ok(jac_t.transpose())
 }
 
 /// Compute translation end-effector Jacobian of point, and rotation Jacobian of axis.
@@ -597,17 +610,12 @@ pub fn jac_point_axis(
     data: &mut crate::Data,
     point: &crate::Vec3,
     axis: &crate::Vec3,
-    body: i32,
-) -> crate::Jacobian6xN {
-    debug_assert!(
-        (body as usize) < data.model.nbody(),
-        "body index {} out of bounds (nbody = {})",
-        body,
-        data.model.nbody()
-    );
+    body: impl crate::IntoId<crate::BodyId>,
+) -> crate::Result<crate::Jacobian6xN> {
+    let body = body.into_id(data.model())?;
     let nv = data.model.nv();
     let mut jac_t = crate::JacobianNx6::zeros(nv);
-    unsafe {
+    crate::handlers::guard(|| unsafe {
         let ptr = jac_t.as_mut_ptr();
         mujoco_sys::mj_jacPointAxis(
             data.model.as_ptr(),
@@ -616,24 +624,23 @@ pub fn jac_point_axis(
             ptr.add(3 * nv),
             point.as_ptr(),
             axis.as_ptr(),
-            body,
+            body.index() as i32,
         );
-    }
-    jac_t.transpose()
+    })?;
+    Ok(jac_t.transpose())
 }
 
 /// Compute 3/6-by-nv Jacobian time derivative of global point attached to given body.
 /// Returns a 6×nv Jacobian time derivative matrix (top 3 rows: position, bottom 3 rows: rotation).
-pub fn jac_dot(data: &crate::Data, point: &crate::Vec3, body: i32) -> crate::Jacobian6xN {
-    debug_assert!(
-        (body as usize) < data.model.nbody(),
-        "body index {} out of bounds (nbody = {})",
-        body,
-        data.model.nbody()
-    );
+pub fn jac_dot(
+    data: &crate::Data,
+    point: &crate::Vec3,
+    body: impl crate::IntoId<crate::BodyId>,
+) -> crate::Result<crate::Jacobian6xN> {
+    let body = body.into_id(data.model())?;
     let nv = data.model.nv();
     let mut jac_t = crate::JacobianNx6::zeros(nv);
-    unsafe {
+    crate::handlers::guard(|| unsafe {
         let ptr = jac_t.as_mut_ptr();
         mujoco_sys::mj_jacDot(
             data.model.as_ptr(),
@@ -641,30 +648,28 @@ pub fn jac_dot(data: &crate::Data, point: &crate::Vec3, body: i32) -> crate::Jac
             ptr,
             ptr.add(3 * nv),
             point.as_ptr(),
-            body,
+            body.index() as i32,
         );
-    }
-    jac_t.transpose()
+    })?;
+    Ok(jac_t.transpose())
 }
 
 /// Compute subtree angular momentum matrix.
 /// Returns a 3×nv angular momentum matrix.
-pub fn angmom_mat(data: &mut crate::Data, body: i32) -> crate::Jacobian3xN {
-    debug_assert!(
-        (body as usize) < data.model.nbody(),
-        "body index {} out of bounds (nbody = {})",
-        body,
-        data.model.nbody()
-    );
+pub fn angmom_mat(
+    data: &mut crate::Data,
+    body: impl crate::IntoId<crate::BodyId>,
+) -> crate::Result<crate::Jacobian3xN> {
+    let body = body.into_id(data.model())?;
     let nv = data.model.nv();
     let mut mat_t = crate::JacobianNx3::zeros(nv);
-    unsafe {
+    crate::handlers::guard(|| unsafe {
         mujoco_sys::mj_angmomMat(
             data.model.as_ptr(),
             data.as_mut_ptr(),
             mat_t.as_mut_ptr(),
-            body,
+            body.index() as i32,
         );
-    }
-    mat_t.transpose()
+    })?;
+    Ok(mat_t.transpose())
 }
@@ -231,7 +231,10 @@ impl<'a> Data<'a> {
 
     /// Get mocap_pos at index as Vec3
     pub fn get_mocap_pos(&self, idx: usize) -> crate::Vec3 {
-        debug_assert!(idx < self.model.nmocap(), "mocap_pos index out of bounds");
+        assert!(
+            idx < self.model.nmocap(),
+            "mocap_pos index {idx} out of bounds"
+        );
         let data = self.mocap_pos();
         let i = idx * 3;
         crate::Vec3::new(data[i], data[i + 1], data[i + 2])
@@ -239,7 +242,10 @@ impl<'a> Data<'a> {
 
     /// Set mocap_pos at index from Vec3
     pub fn set_mocap_pos(&mut self, idx: usize, value: &crate::Vec3) {
-        debug_assert!(idx < self.model.nmocap(), "mocap_pos index out of bounds");
+        assert!(
+            idx < self.model.nmocap(),
+            "mocap_pos index {idx} out of bounds"
+        );
         let data = self.mocap_pos_mut();
         let i = idx * 3;
         data[i] = value[0];
@@ -261,7 +267,10 @@ impl<'a> Data<'a> {
 
     /// Get mocap_quat at index as UnitQuaternion
     pub fn get_mocap_quat(&self, idx: usize) -> crate::Quat {
-        debug_assert!(idx < self.model.nmocap(), "mocap_quat index out of bounds");
+        assert!(
+            idx < self.model.nmocap(),
+            "mocap_quat index {idx} out of bounds"
+        );
         let data = self.mocap_quat();
         let i = idx * 4;
         // MuJoCo uses (w, x, y, z), nalgebra Quaternion::new takes (w, x, y, z)
@@ -275,7 +284,10 @@ impl<'a> Data<'a> {
 
     /// Set mocap_quat at index from UnitQuaternion
     pub fn set_mocap_quat(&mut self, idx: usize, value: &crate::Quat) {
-        debug_assert!(idx < self.model.nmocap(), "mocap_quat index out of bounds");
+        assert!(
+            idx < self.model.nmocap(),
+            "mocap_quat index {idx} out of bounds"
+        );
         let data = self.mocap_quat_mut();
         let i = idx * 4;
         data[i] = value.w;
@@ -367,8 +379,9 @@ impl<'a> Data<'a> {
     }
 
     /// Get xpos at index as Vec3
-    pub fn get_xpos(&self, idx: usize) -> crate::Vec3 {
-        debug_assert!(idx < self.model.nbody(), "xpos index out of bounds");
+    pub fn get_xpos(&self, id: crate::BodyId) -> crate::Vec3 {
+        let idx = id.index();
+        assert!(idx < self.model.nbody(), "xpos index {idx} out of bounds");
         let data = self.xpos();
         let i = idx * 3;
         crate::Vec3::new(data[i], data[i + 1], data[i + 2])
@@ -385,8 +398,9 @@ impl<'a> Data<'a> {
     }
 
     /// Get xquat at index as UnitQuaternion
-    pub fn get_xquat(&self, idx: usize) -> crate::Quat {
-        debug_assert!(idx < self.model.nbody(), "xquat index out of bounds");
+    pub fn get_xquat(&self, id: crate::BodyId) -> crate::Quat {
+        let idx = id.index();
+        assert!(idx < self.model.nbody(), "xquat index {idx} out of bounds");
         let data = self.xquat();
         let i = idx * 4;
         // MuJoCo uses (w, x, y, z), nalgebra Quaternion::new takes (w, x, y, z)
@@ -409,8 +423,9 @@ impl<'a> Data<'a> {
     }
 
     /// Get xmat at index as Mat3
-    pub fn get_xmat(&self, idx: usize) -> crate::Mat3 {
-        debug_assert!(idx < self.model.nbody(), "xmat index out of bounds");
+    pub fn get_xmat(&self, id: crate::BodyId) -> crate::Mat3 {
+        let idx = id.index();
+        assert!(idx < self.model.nbody(), "xmat index {idx} out of bounds");
         let data = self.xmat();
         let i = idx * 9;
         // MuJoCo stores row-major, nalgebra is column-major
@@ -428,8 +443,9 @@ impl<'a> Data<'a> {
     }
 
     /// Get xipos at index as Vec3
-    pub fn get_xipos(&self, idx: usize) -> crate::Vec3 {
-        debug_assert!(idx < self.model.nbody(), "xipos index out of bounds");
+    pub fn get_xipos(&self, id: crate::BodyId) -> crate::Vec3 {
+        let idx = id.index();
+        assert!(idx < self.model.nbody(), "xipos index {idx} out of bounds");
         let data = self.xipos();
         let i = idx * 3;
         crate::Vec3::new(data[i], data[i + 1], data[i + 2])
@@ -446,8 +462,9 @@ impl<'a> Data<'a> {
     }
 
     /// Get ximat at index as Mat3
-    pub fn get_ximat(&self, idx: usize) -> crate::Mat3 {
-        debug_assert!(idx < self.model.nbody(), "ximat index out of bounds");
+    pub fn get_ximat(&self, id: crate::BodyId) -> crate::Mat3 {
+        let idx = id.index();
+        assert!(idx < self.model.nbody(), "ximat index {idx} out of bounds");
         let data = self.ximat();
         let i = idx * 9;
         // MuJoCo stores row-major, nalgebra is column-major
@@ -485,8 +502,12 @@ impl<'a> Data<'a> {
     }
 
     /// Get geom_xpos at index as Vec3
-    pub fn get_geom_xpos(&self, idx: usize) -> crate::Vec3 {
-        debug_assert!(idx < self.model.ngeom(), "geom_xpos index out of bounds");
+    pub fn get_geom_xpos(&self, id: crate::GeomId) -> crate::Vec3 {
+        let idx = id.index();
+        assert!(
+            idx < self.model.ngeom(),
+            "geom_xpos index {idx} out of bounds"
+        );
         let data = self.geom_xpos();
         let i = idx * 3;
         crate::Vec3::new(data[i], data[i + 1], data[i + 2])
@@ -503,8 +524,12 @@ impl<'a> Data<'a> {
     }
 
     /// Get geom_xmat at index as Mat3
-    pub fn get_geom_xmat(&self, idx: usize) -> crate::Mat3 {
-        debug_assert!(idx < self.model.ngeom(), "geom_xmat index out of bounds");
+    pub fn get_geom_xmat(&self, id: crate::GeomId) -> crate::Mat3 {
+        let idx = id.index();
+        assert!(
+            idx < self.model.ngeom(),
+            "geom_xmat index {idx} out of bounds"
+        );
         let data = self.geom_xmat();
         let i = idx * 9;
         // MuJoCo stores row-major, nalgebra is column-major
@@ -522,8 +547,12 @@ impl<'a> Data<'a> {
     }
 
     /// Get site_xpos at index as Vec3
-    pub fn get_site_xpos(&self, idx: usize) -> crate::Vec3 {
-        debug_assert!(idx < self.model.nsite(), "site_xpos index out of bounds");
+    pub fn get_site_xpos(&self, id: crate::SiteId) -> crate::Vec3 {
+        let idx = id.index();
+        assert!(
+            idx < self.model.nsite(),
+            "site_xpos index {idx} out of bounds"
+        );
         let data = self.site_xpos();
         let i = idx * 3;
         crate::Vec3::new(data[i], data[i + 1], data[i + 2])
@@ -540,8 +569,12 @@ impl<'a> Data<'a> {
     }
 
     /// Get site_xmat at index as Mat3
-    pub fn get_site_xmat(&self, idx: usize) -> crate::Mat3 {
-        debug_assert!(idx < self.model.nsite(), "site_xmat index out of bounds");
+    pub fn get_site_xmat(&self, id: crate::SiteId) -> crate::Mat3 {
+        let idx = id.index();
+        assert!(
+            idx < self.model.nsite(),
+            "site_xmat index {idx} out of bounds"
+        );
         let data = self.site_xmat();
         let i = idx * 9;
         // MuJoCo stores row-major, nalgebra is column-major
@@ -605,8 +638,12 @@ impl<'a> Data<'a> {
     }
 
     /// Get subtree_com at index as Vec3
-    pub fn get_subtree_com(&self, idx: usize) -> crate::Vec3 {
-        debug_assert!(idx < self.model.nbody(), "subtree_com index out of bounds");
+    pub fn get_subtree_com(&self, id: crate::BodyId) -> crate::Vec3 {
+        let idx = id.index();
+        assert!(
+            idx < self.model.nbody(),
+            "subtree_com index {idx} out of bounds"
+        );
         let data = self.subtree_com();
         let i = idx * 3;
         crate::Vec3::new(data[i], data[i + 1], data[i + 2])
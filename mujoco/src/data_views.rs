@@ -0,0 +1,324 @@
+//! Named, typed views of the simulation state of one element, similar to
+//! `MjData.body("name")` in Python
+//!
+//! A view wraps either `&Data` ([`Data::body`], [`Data::joint`], ...) or `&mut Data`
+//! ([`Data::joint_mut`], [`Data::actuator_mut`], ...) and slices the flat `mjData`
+//! arrays using the element's addresses in the model.
+
+use crate::error::Result;
+use crate::ids::IntoId;
+use crate::ids::sealed::Sealed;
+use crate::model_views::{quat, vec3};
+use crate::{ActuatorId, BodyId, Data, JointId, Mat3, Pose, Quat, SensorId, Vec3, Vec6};
+use std::ops::Range;
+
+/// Implement read accessors for both the shared and the mutable form of a view
+macro_rules! impl_read {
+    ($name:ident { $($body:tt)* }) => {
+        impl<'m> $name<&Data<'m>> {
+            $($body)*
+        }
+
+        impl<'m> $name<&mut Data<'m>> {
+            $($body)*
+        }
+    };
+}
+
+macro_rules! data_view {
+    ($(#[$doc:meta])* $name:ident, $method:ident, $method_mut:ident, $id:ident) => {
+        $(#[$doc])*
+        #[derive(Debug)]
+        pub struct $name<D> {
+            data: D,
+            id: usize,
+        }
+
+        impl_read!($name {
+            /// Id of the element
+            pub fn id(&self) -> $id {
+                $id::from_index(self.id)
+            }
+
+            /// Name of the element, empty if it is unnamed
+            pub fn name(&self) -> &str {
+                self.id().name(self.data.model()).unwrap_or("")
+            }
+        });
+
+        impl<'m> Data<'m> {
+            #[doc = concat!("View of a ", stringify!($method), ", given its name or id")]
+            pub fn $method(&self, element: impl IntoId<$id>) -> Result<$name<&Self>> {
+                let id = element.into_id(self.model())?.index();
+                Ok($name { data: self, id })
+            }
+
+            #[doc = concat!("Mutable view of a ", stringify!($method), ", given its name or id")]
+            pub fn $method_mut(&mut self, element: impl IntoId<$id>) -> Result<$name<&mut Self>> {
+                let id = element.into_id(self.model())?.index();
+                Ok($name { data: self, id })
+            }
+        }
+    };
+}
+
+data_view!(
+    /// Body state, see [`Data::body`]
+    DataBody,
+    body,
+    body_mut,
+    BodyId
+);
+data_view!(
+    /// Joint state, see [`Data::joint`]
+    DataJoint,
+    joint,
+    joint_mut,
+    JointId
+);
+data_view!(
+    /// Actuator state, see [`Data::actuator`]
+    DataActuator,
+    actuator,
+    actuator_mut,
+    ActuatorId
+);
+data_view!(
+    /// Sensor values, see [`Data::sensor`]
+    DataSensor,
+    sensor,
+    sensor_mut,
+    SensorId
+);
+
+impl_read!(DataBody {
+    /// Cartesian position of the body frame
+    pub fn xpos(&self) -> Vec3 {
+        vec3(self.data.xpos(), self.id)
+    }
+
+    /// Cartesian orientation of the body frame
+    pub fn xquat(&self) -> Quat {
+        quat(self.data.xquat(), self.id)
+    }
+
+    /// Cartesian orientation of the body frame as a rotation matrix
+    pub fn xmat(&self) -> Mat3 {
+        self.data.get_xmat(self.id())
+    }
+
+    /// Cartesian pose of the body frame
+    pub fn pose(&self) -> Pose {
+        Pose::from_parts(self.xpos().into(), self.xquat())
+    }
+
+    /// Cartesian position of the center of mass
+    pub fn xipos(&self) -> Vec3 {
+        vec3(self.data.xipos(), self.id)
+    }
+
+    /// Center of mass of the subtree rooted at this body
+    pub fn subtree_com(&self) -> Vec3 {
+        vec3(self.data.subtree_com(), self.id)
+    }
+
+    /// Velocity (rotation, then translation) in the frame centered at the subtree's center of mass
+    pub fn cvel(&self) -> Vec6 {
+        Vec6::from_column_slice(&self.data.cvel()[6 * self.id..6 * self.id + 6])
+    }
+
+    /// Applied Cartesian force and torque
+    pub fn xfrc_applied(&self) -> Vec6 {
+        Vec6::from_column_slice(&self.data.xfrc_applied()[6 * self.id..6 * self.id + 6])
+    }
+});
+
+impl<'m> DataBody<&mut Data<'m>> {
+    /// Apply a Cartesian force and torque at the center of mass, in world coordinates
+    pub fn set_xfrc_applied(&mut self, force: &Vec3, torque: &Vec3) {
+        let xfrc = &mut self.data.xfrc_applied_mut()[6 * self.id..6 * self.id + 6];
+        xfrc[..3].copy_from_slice(force.as_slice());
+        xfrc[3..].copy_from_slice(torque.as_slice());
+    }
+}
+
+impl_read!(DataJoint {
+    fn qpos_range(&self) -> Range<usize> {
+        let model = self.data.model();
+        let adr = model.jnt_qposadr()[self.id] as usize;
+        adr..adr + crate::joint_nq(model.jnt_type()[self.id])
+    }
+
+    fn dof_range(&self) -> Range<usize> {
+        let model = self.data.model();
+        let adr = model.jnt_dofadr()[self.id] as usize;
+        adr..adr + crate::joint_nv(model.jnt_type()[self.id])
+    }
+
+    /// Position coordinates (7 for free, 4 for ball, 1 for hinge and slide joints)
+    pub fn qpos(&self) -> &[f64] {
+        &self.data.qpos()[self.qpos_range()]
+    }
+
+    /// Velocities (6 for free, 3 for ball, 1 for hinge and slide joints)
+    pub fn qvel(&self) -> &[f64] {
+        &self.data.qvel()[self.dof_range()]
+    }
+
+    /// Accelerations
+    pub fn qacc(&self) -> &[f64] {
+        &self.data.qacc()[self.dof_range()]
+    }
+
+    /// Actuator forces acting on the joint's degrees of freedom
+    pub fn qfrc_actuator(&self) -> &[f64] {
+        &self.data.qfrc_actuator()[self.dof_range()]
+    }
+
+    /// Applied generalized forces
+    pub fn qfrc_applied(&self) -> &[f64] {
+        &self.data.qfrc_applied()[self.dof_range()]
+    }
+});
+
+impl<'m> DataJoint<&mut Data<'m>> {
+    /// Mutable position coordinates
+    pub fn qpos_mut(&mut self) -> &mut [f64] {
+        let range = self.qpos_range();
+        &mut self.data.qpos_mut()[range]
+    }
+
+    /// Mutable velocities
+    pub fn qvel_mut(&mut self) -> &mut [f64] {
+        let range = self.dof_range();
+        &mut self.data.qvel_mut()[range]
+    }
+
+    /// Mutable applied generalized forces
+    pub fn qfrc_applied_mut(&mut self) -> &mut [f64] {
+        let range = self.dof_range();
+        &mut self.data.qfrc_applied_mut()[range]
+    }
+}
+
+impl_read!(DataActuator {
+    /// Control input
+    pub fn ctrl(&self) -> f64 {
+        self.data.ctrl()[self.id]
+    }
+
+    /// Scalar actuator force
+    pub fn force(&self) -> f64 {
+        self.data.actuator_force()[self.id]
+    }
+
+    /// Length of the transmission
+    pub fn length(&self) -> f64 {
+        self.data.actuator_length()[self.id]
+    }
+
+    /// Velocity of the transmission
+    pub fn velocity(&self) -> f64 {
+        self.data.actuator_velocity()[self.id]
+    }
+
+    /// Activations, empty for actuators without activation dynamics
+    pub fn act(&self) -> &[f64] {
+        let model = self.data.model();
+        let adr = model.actuator_actadr()[self.id].max(0) as usize;
+        &self.data.act()[adr..adr + model.actuator_actnum()[self.id] as usize]
+    }
+});
+
+impl<'m> DataActuator<&mut Data<'m>> {
+    /// Set the control input
+    pub fn set_ctrl(&mut self, ctrl: f64) {
+        self.data.ctrl_mut()[self.id] = ctrl;
+    }
+}
+
+impl_read!(DataSensor {
+    fn range(&self) -> Range<usize> {
+        let model = self.data.model();
+        let adr = model.sensor_adr()[self.id] as usize;
+        adr..adr + model.sensor_dim()[self.id] as usize
+    }
+
+    /// Sensor values
+    pub fn data(&self) -> &[f64] {
+        &self.data.sensordata()[self.range()]
+    }
+});
+
+impl<'m> DataSensor<&mut Data<'m>> {
+    /// Mutable sensor values, e.g. to inject measurements
+    pub fn data_mut(&mut self) -> &mut [f64] {
+        let range = self.range();
+        &mut self.data.sensordata_mut()[range]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_data_views() {
+        let model = crate::Model::from_file(crate::tests::test_xml_path()).unwrap();
+        let mut data = Data::new(&model);
+        data.joint_mut("joint2").unwrap().qpos_mut()[0] = 0.5;
+        data.actuator_mut("joint3").unwrap().set_ctrl(0.2);
+        crate::forward(&mut data).unwrap();
+
+        assert_eq!(data.joint("joint2").unwrap().qpos(), &[0.5]);
+        assert_eq!(data.qpos()[1], 0.5);
+        assert_eq!(data.actuator("joint3").unwrap().ctrl(), 0.2);
+        assert_eq!(data.ctrl(), &[0.0, 0.0, 0.2]);
+        assert!(data.actuator("joint3").unwrap().force() > 0.0);
+
+        let body = data.body("end_effector").unwrap();
+        assert_eq!(body.name(), "end_effector");
+        assert_eq!(body.xpos(), data.get_xpos(body.id()));
+        assert_eq!(body.xquat(), data.get_xquat(body.id()));
+        let pose = body.pose();
+        assert_eq!(pose.translation.vector, body.xpos());
+        assert!((pose.rotation.to_rotation_matrix().into_inner() - body.xmat()).amax() < 1e-12);
+        assert!(data.body("missing").is_err());
+        let by_id = data.body(body.id()).unwrap();
+        assert_eq!(by_id.name(), "end_effector");
+    }
+
+    #[test]
+    fn test_free_joint_and_sensor() {
+        let model = crate::Spec::from_str(
+            r#"<mujoco>
+              <worldbody>
+                <body name="ball" pos="0 0 1">
+                  <freejoint name="free" />
+                  <geom type="sphere" size="0.1" />
+                </body>
+              </worldbody>
+              <sensor>
+                <framepos name="position" objtype="body" objname="ball" />
+                <framelinvel name="velocity" objtype="body" objname="ball" />
+              </sensor>
+            </mujoco>"#,
+        )
+        .unwrap()
+        .compile()
+        .unwrap();
+        let mut data = Data::new(&model);
+        {
+            let mut free = data.joint_mut("free").unwrap();
+            assert_eq!(free.qpos().len(), 7);
+            assert_eq!(free.qvel().len(), 6);
+            free.qvel_mut()[0] = 2.0;
+        }
+        crate::forward(&mut data).unwrap();
+
+        assert_eq!(data.sensor("position").unwrap().data(), &[0.0, 0.0, 1.0]);
+        assert_eq!(data.sensor("velocity").unwrap().data(), &[2.0, 0.0, 0.0]);
+        assert_eq!(data.body("ball").unwrap().cvel()[3], 2.0);
+    }
+}
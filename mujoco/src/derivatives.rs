@@ -0,0 +1,194 @@
+//! Finite-difference derivatives of the dynamics (mjd_transitionFD, mjd_inverseFD)
+//!
+//! MuJoCo writes row-major matrices; the results here are converted to nalgebra's
+//! column-major `DMatrix` and follow the control-theory convention (rows are outputs,
+//! columns are inputs).
+
+use crate::DMatrix;
+use crate::error::Result;
+
+/// Row-major `rows`×`cols` buffer handed to MuJoCo
+fn row_major(rows: usize, cols: usize) -> DMatrix<f64> {
+    // Stored column-major as cols×rows, its memory is the row-major rows×cols layout
+    DMatrix::zeros(cols, rows)
+}
+
+/// Linearization of one step: `x' = A x + B u`, `y = C x + D u`
+///
+/// `x` is the state `(dq, qvel, act)` of size `2*nv + na`, where `dq` is a
+/// tangent-space position difference, `u` the controls and `y` the sensor data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransitionDerivatives {
+    /// State transition, `(2*nv + na)`×`(2*nv + na)`
+    pub a: DMatrix<f64>,
+    /// Control transition, `(2*nv + na)`×`nu`
+    pub b: DMatrix<f64>,
+    /// Sensor/state Jacobian, `nsensordata`×`(2*nv + na)`
+    pub c: DMatrix<f64>,
+    /// Sensor/control Jacobian, `nsensordata`×`nu`
+    pub d: DMatrix<f64>,
+}
+
+/// Finite-difference transition and sensor Jacobians around the current state
+///
+/// `eps` is the perturbation size; `centered` uses central instead of forward
+/// differences (twice the cost, more accurate). The state of `data` is restored
+/// afterwards.
+pub fn transition_fd(
+    data: &mut crate::Data,
+    eps: f64,
+    centered: bool,
+) -> Result<TransitionDerivatives> {
+    let model = data.model();
+    let nx = 2 * model.nv() + model.na();
+    let (nu, ns) = (model.nu(), model.nsensordata());
+    let mut a = row_major(nx, nx);
+    let mut b = row_major(nx, nu);
+    let mut c = row_major(ns, nx);
+    let mut d = row_major(ns, nu);
+    crate::handlers::guard(|| unsafe {
+        mujoco_sys::mjd_transitionFD(
+            data.model.as_ptr(),
+            data.ptr,
+            eps,
+            centered as u8,
+            a.as_mut_ptr(),
+            b.as_mut_ptr(),
+            c.as_mut_ptr(),
+            d.as_mut_ptr(),
+        )
+    })?;
+    Ok(TransitionDerivatives {
+        a: a.transpose(),
+        b: b.transpose(),
+        c: c.transpose(),
+        d: d.transpose(),
+    })
+}
+
+/// Jacobians of the inverse dynamics `(f, s) = mj_inverse(q, v, a)`
+///
+/// `f` is the generalized force `qfrc_inverse`, `s` the sensor data and `M` the
+/// sparse inertia matrix `qM` (of size `nM`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct InverseDerivatives {
+    /// `df/dq`, `nv`×`nv`
+    pub dfdq: DMatrix<f64>,
+    /// `df/dv`, `nv`×`nv`
+    pub dfdv: DMatrix<f64>,
+    /// `df/da`, `nv`×`nv`
+    pub dfda: DMatrix<f64>,
+    /// `ds/dq`, `nsensordata`×`nv`
+    pub dsdq: DMatrix<f64>,
+    /// `ds/dv`, `nsensordata`×`nv`
+    pub dsdv: DMatrix<f64>,
+    /// `ds/da`, `nsensordata`×`nv`
+    pub dsda: DMatrix<f64>,
+    /// `dM/dq`, `nM`×`nv`
+    pub dmdq: DMatrix<f64>,
+}
+
+/// Finite-difference Jacobians of the inverse dynamics around the current state
+///
+/// With `actuation`, the actuator forces are subtracted from `f`, so it is the
+/// force that remains to be explained (e.g. by system identification).
+pub fn inverse_fd(data: &mut crate::Data, eps: f64, actuation: bool) -> Result<InverseDerivatives> {
+    let model = data.model();
+    let (nv, ns, nm) = (model.nv(), model.nsensordata(), model.nM());
+    // MuJoCo writes these transposed (inputs×outputs, row-major), which is exactly
+    // the column-major layout of the outputs×inputs matrices
+    let mut dfdq = DMatrix::zeros(nv, nv);
+    let mut dfdv = DMatrix::zeros(nv, nv);
+    let mut dfda = DMatrix::zeros(nv, nv);
+    let mut dsdq = DMatrix::zeros(ns, nv);
+    let mut dsdv = DMatrix::zeros(ns, nv);
+    let mut dsda = DMatrix::zeros(ns, nv);
+    let mut dmdq = DMatrix::zeros(nm, nv);
+    crate::handlers::guard(|| unsafe {
+        mujoco_sys::mjd_inverseFD(
+            data.model.as_ptr(),
+            data.ptr,
+            eps,
+            actuation as u8,
+            dfdq.as_mut_ptr(),
+            dfdv.as_mut_ptr(),
+            dfda.as_mut_ptr(),
+            dsdq.as_mut_ptr(),
+            dsdv.as_mut_ptr(),
+            dsda.as_mut_ptr(),
+            dmdq.as_mut_ptr(),
+        )
+    })?;
+    Ok(InverseDerivatives {
+        dfdq,
+        dfdv,
+        dfda,
+        dsdq,
+        dsdv,
+        dsda,
+        dmdq,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transition_fd_layout() {
+        let model = crate::Model::from_file(crate::tests::test_xml_path()).unwrap();
+        let mut data = crate::Data::new(&model);
+        data.qpos_mut().copy_from_slice(&[0.3, -0.2, 0.4]);
+        let (nv, nu) = (model.nv(), model.nu());
+        let nx = 2 * nv + model.na();
+
+        let derivatives = transition_fd(&mut data, 1e-6, true).unwrap();
+        assert_eq!(derivatives.a.shape(), (nx, nx));
+        assert_eq!(derivatives.b.shape(), (nx, nu));
+        assert_eq!(derivatives.c.shape(), (0, nx));
+
+        // Compare against the raw row-major output
+        let mut a = vec![0.0; nx * nx];
+        let mut b = vec![0.0; nx * nu];
+        unsafe {
+            mujoco_sys::mjd_transitionFD(
+                model.as_ptr(),
+                data.as_mut_ptr(),
+                1e-6,
+                1,
+                a.as_mut_ptr(),
+                b.as_mut_ptr(),
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+            );
+        }
+        for row in 0..nx {
+            for col in 0..nx {
+                assert_eq!(derivatives.a[(row, col)], a[row * nx + col]);
+            }
+            for col in 0..nu {
+                assert_eq!(derivatives.b[(row, col)], b[row * nu + col]);
+            }
+        }
+        // Raising the first servo's setpoint accelerates the first joint
+        assert!(derivatives.b[(nv, 0)] > 0.0);
+    }
+
+    #[test]
+    fn test_inverse_fd_mass_matrix() {
+        let model = crate::Model::from_file(crate::tests::test_xml_path()).unwrap();
+        let mut data = crate::Data::new(&model);
+        data.qpos_mut().copy_from_slice(&[0.3, -0.2, 0.4]);
+        let derivatives = inverse_fd(&mut data, 1e-6, false).unwrap();
+        assert_eq!(derivatives.dmdq.shape(), (model.nM(), model.nv()));
+
+        // df/da is the (dense, symmetric) mass matrix
+        crate::forward(&mut data).unwrap();
+        let mut mass = vec![0.0; model.nv() * model.nv()];
+        unsafe { mujoco_sys::mj_fullM(model.as_ptr(), mass.as_mut_ptr(), data.raw().qM) };
+        for (i, value) in mass.iter().enumerate() {
+            let (row, col) = (i / model.nv(), i % model.nv());
+            assert!((derivatives.dfda[(row, col)] - value).abs() < 1e-4);
+        }
+    }
+}
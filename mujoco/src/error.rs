@@ -0,0 +1,181 @@
+use std::fmt;
+use std::path::PathBuf;
+
+/// Convenience alias for results returned by this crate.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Error type shared by all fallible operations in this crate.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// The XML could not be parsed into an mjSpec.
+    Parse(ErrorInfo),
+    /// The mjSpec could not be compiled into an mjModel.
+    Compile(ErrorInfo),
+    /// Reading or writing a file failed.
+    Io(std::io::Error),
+    /// A name or path cannot be passed to MuJoCo (e.g. it contains a NUL byte).
+    InvalidName(String),
+    /// The named element or file does not exist.
+    NotFound(String),
+    /// An element or file with the same name already exists.
+    DuplicateName(String),
+    /// A binary buffer (e.g. MJB) is malformed or too large.
+    InvalidBuffer(String),
+    /// MuJoCo failed to allocate the named structure.
+    Allocation(&'static str),
+    /// MuJoCo reported an error at runtime.
+    Engine(String),
+    /// An element id is out of range for the model it is used with.
+    InvalidId {
+        /// Type of the element
+        kind: mujoco_sys::mjtObj,
+        /// Offending id
+        id: usize,
+        /// Number of elements of this type in the model
+        count: usize,
+    },
+    /// A physics option is outside its valid range.
+    InvalidOption(String),
+    /// Saved state does not fit the structure of the model it is restored into.
+    ModelMismatch(String),
+    /// A thread pool cannot be created or bound as requested.
+    ThreadPool(String),
+    /// The operation is not supported by this build of the crate.
+    Unsupported(&'static str),
+    /// The linked MuJoCo library does not match the headers the bindings were generated from.
+    VersionMismatch {
+        /// Version of the headers (`mjVERSION_HEADER`)
+        header: u32,
+        /// Version reported by the library (`mj_version`)
+        library: u32,
+    },
+}
+
+/// Message and source location reported by MuJoCo's parser or compiler.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ErrorInfo {
+    /// Message as reported by MuJoCo
+    pub message: String,
+    /// File being loaded, if the model came from a file
+    pub file: Option<PathBuf>,
+    /// Line in the XML source, if MuJoCo reported one
+    pub line: Option<usize>,
+    /// Column in the XML source, if MuJoCo reported one
+    pub column: Option<usize>,
+}
+
+impl ErrorInfo {
+    /// Build from a MuJoCo error message, extracting line/column information
+    pub(crate) fn new(message: impl Into<String>, file: Option<PathBuf>) -> Self {
+        let message = message.into();
+        // MuJoCo reports "line N" for schema/compiler errors, tinyxml2 uses "Row=N Col=M"
+        let line = number_after(&message, "line ").or_else(|| number_after(&message, "Row="));
+        let column = number_after(&message, "column ").or_else(|| number_after(&message, "Col="));
+        Self {
+            message,
+            file,
+            line,
+            column,
+        }
+    }
+}
+
+fn number_after(message: &str, key: &str) -> Option<usize> {
+    let start = message.find(key)? + key.len();
+    let digits: String = message[start..]
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect();
+    digits.parse().ok()
+}
+
+impl fmt::Display for ErrorInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}", file.display())?;
+            if let Some(line) = self.line {
+                write!(f, ":{line}")?;
+            }
+            f.write_str(": ")?;
+        }
+        f.write_str(self.message.trim_end())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(info) => write!(f, "Failed to parse model: {info}"),
+            Error::Compile(info) => write!(f, "Failed to compile model: {info}"),
+            Error::Io(e) => write!(f, "I/O error: {e}"),
+            Error::InvalidName(name) => write!(f, "Invalid name '{name}'"),
+            Error::NotFound(name) => write!(f, "'{name}' not found"),
+            Error::DuplicateName(name) => write!(f, "'{name}' already exists"),
+            Error::InvalidBuffer(reason) => write!(f, "Invalid buffer: {reason}"),
+            Error::Allocation(what) => write!(f, "Failed to allocate {what}"),
+            Error::Engine(message) => write!(f, "MuJoCo error: {message}"),
+            Error::InvalidId { kind, id, count } => {
+                write!(f, "{kind:?} id {id} out of range (model has {count})")
+            }
+            Error::InvalidOption(reason) => write!(f, "Invalid option: {reason}"),
+            Error::ModelMismatch(reason) => write!(f, "Incompatible model: {reason}"),
+            Error::ThreadPool(reason) => write!(f, "Thread pool error: {reason}"),
+            Error::Unsupported(what) => write!(f, "Not supported: {what}"),
+            Error::VersionMismatch { header, library } => write!(
+                f,
+                "MuJoCo library version {library} does not match header version {header}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+/// Convert a Rust string into a CString, reporting interior NUL bytes as InvalidName
+pub(crate) fn to_cstring(name: &str) -> Result<std::ffi::CString> {
+    std::ffi::CString::new(name).map_err(|_| Error::InvalidName(name.to_owned()))
+}
+
+/// Read a NUL-terminated MuJoCo error buffer into a String
+pub(crate) fn error_buf_to_string(buf: &[u8]) -> String {
+    std::ffi::CStr::from_bytes_until_nul(buf)
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_else(|_| String::from_utf8_lossy(buf).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_info_location() {
+        let info = ErrorInfo::new(
+            "Schema violation: unrecognized element\nElement 'freejoin', line 4\n",
+            Some("robot.xml".into()),
+        );
+        assert_eq!(info.line, Some(4));
+        assert_eq!(info.column, None);
+        assert!(
+            info.to_string()
+                .starts_with("robot.xml:4: Schema violation")
+        );
+
+        let info = ErrorInfo::new("XML Error: Error=XML_ERROR_PARSING Row=3 Col=7", None);
+        assert_eq!(info.line, Some(3));
+        assert_eq!(info.column, Some(7));
+    }
+}
@@ -0,0 +1,68 @@
+//! Bit flags of the physics options
+//! Generated by generate_from_introspect.py - DO NOT EDIT MANUALLY
+
+use crate::Model;
+use mujoco_sys::{mjtDisableBit, mjtEnableBit};
+
+bitflags::bitflags! {
+    /// Flags of `mjOption::disableflags` (mjtDisableBit)
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct DisableFlags: u32 {
+        const CONSTRAINT = mjtDisableBit::CONSTRAINT as u32;
+        const EQUALITY = mjtDisableBit::EQUALITY as u32;
+        const FRICTIONLOSS = mjtDisableBit::FRICTIONLOSS as u32;
+        const LIMIT = mjtDisableBit::LIMIT as u32;
+        const CONTACT = mjtDisableBit::CONTACT as u32;
+        const SPRING = mjtDisableBit::SPRING as u32;
+        const DAMPER = mjtDisableBit::DAMPER as u32;
+        const GRAVITY = mjtDisableBit::GRAVITY as u32;
+        const CLAMPCTRL = mjtDisableBit::CLAMPCTRL as u32;
+        const WARMSTART = mjtDisableBit::WARMSTART as u32;
+        const FILTERPARENT = mjtDisableBit::FILTERPARENT as u32;
+        const ACTUATION = mjtDisableBit::ACTUATION as u32;
+        const REFSAFE = mjtDisableBit::REFSAFE as u32;
+        const SENSOR = mjtDisableBit::SENSOR as u32;
+        const MIDPHASE = mjtDisableBit::MIDPHASE as u32;
+        const EULERDAMP = mjtDisableBit::EULERDAMP as u32;
+        const AUTORESET = mjtDisableBit::AUTORESET as u32;
+        const NATIVECCD = mjtDisableBit::NATIVECCD as u32;
+        const ISLAND = mjtDisableBit::ISLAND as u32;
+    }
+}
+
+bitflags::bitflags! {
+    /// Flags of `mjOption::enableflags` (mjtEnableBit)
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct EnableFlags: u32 {
+        const OVERRIDE = mjtEnableBit::OVERRIDE as u32;
+        const ENERGY = mjtEnableBit::ENERGY as u32;
+        const FWDINV = mjtEnableBit::FWDINV as u32;
+        const INVDISCRETE = mjtEnableBit::INVDISCRETE as u32;
+        const MULTICCD = mjtEnableBit::MULTICCD as u32;
+        const SLEEP = mjtEnableBit::SLEEP as u32;
+    }
+}
+
+impl Model {
+    /// Flags set in `mjOption::disableflags`, unknown bits are dropped
+    pub fn disable_flags(&self) -> DisableFlags {
+        DisableFlags::from_bits_truncate(self.raw().opt.disableflags as u32)
+    }
+
+    /// Replace `mjOption::disableflags`
+    pub fn set_disable_flags(&mut self, flags: DisableFlags) {
+        self.raw_mut().opt.disableflags = flags.bits() as i32;
+    }
+
+    /// Flags set in `mjOption::enableflags`, unknown bits are dropped
+    pub fn enable_flags(&self) -> EnableFlags {
+        EnableFlags::from_bits_truncate(self.raw().opt.enableflags as u32)
+    }
+
+    /// Replace `mjOption::enableflags`
+    pub fn set_enable_flags(&mut self, flags: EnableFlags) {
+        self.raw_mut().opt.enableflags = flags.bits() as i32;
+    }
+}
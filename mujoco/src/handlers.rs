@@ -0,0 +1,130 @@
+//! Routing of MuJoCo's error and warning callbacks into Rust
+//!
+//! MuJoCo's default `mju_error` handler prints the message and calls `exit`.
+//! [`install_handlers`] replaces it with a handler that unwinds back to the
+//! nearest guarded call (e.g. [`crate::step`]), which then returns
+//! [`Error::Engine`]. Warnings are forwarded to the [`log`] crate.
+//!
+//! Unwinding through MuJoCo's C frames requires them to carry unwind tables;
+//! the vendored build compiles MuJoCo with `-fexceptions` for that reason.
+//! A system-installed library gives no such guarantee, so without the
+//! `vendored-mujoco` feature only the warning handler is installed and engine
+//! errors keep MuJoCo's default behavior of terminating the process.
+//!
+//! Limits of the error routing:
+//! - Only the safe wrappers of this crate catch engine errors. Calling into
+//!   [`crate::sys`] directly while the handler is installed turns an engine
+//!   error into a panic carrying an opaque payload.
+//! - Errors raised on the worker threads of a [`crate::ThreadPool`] cannot
+//!   unwind back to the caller and abort the process.
+//! - After an engine error the affected `Data` may be left half-updated and
+//!   should be reset before it is stepped again.
+
+use crate::error::{Error, Result};
+use std::ffi::{CStr, c_char};
+use std::panic::{AssertUnwindSafe, catch_unwind, resume_unwind};
+use std::sync::OnceLock;
+
+/// Panic payload used to carry a MuJoCo error message up to [`guard`]
+struct EngineError(String);
+
+/// Outcome of the one-time setup performed by the first guarded call
+static INIT: OnceLock<Result<()>> = OnceLock::new();
+
+/// Whether the linked MuJoCo was built with unwind tables, see the module docs
+const UNWIND_SUPPORTED: bool = cfg!(feature = "vendored-mujoco");
+
+unsafe fn message(msg: *const c_char) -> String {
+    if msg.is_null() {
+        return String::new();
+    }
+    unsafe { CStr::from_ptr(msg).to_string_lossy().into_owned() }
+}
+
+extern "C-unwind" fn error_handler(msg: *const c_char) {
+    // resume_unwind skips the panic hook, so nothing is printed to stderr
+    resume_unwind(Box::new(EngineError(unsafe { message(msg) })));
+}
+
+extern "C" fn warning_handler(msg: *const c_char) {
+    log::warn!(target: "mujoco", "{}", unsafe { message(msg) });
+}
+
+/// Install the Rust error and warning handlers process-wide
+///
+/// This is done automatically the first time a guarded function runs; call it
+/// explicitly to re-install the handlers after [`clear_handlers`].
+///
+/// Returns [`Error::Unsupported`] when linking a system-installed MuJoCo, which
+/// may lack the unwind tables the error handler needs. The warning handler is
+/// installed regardless.
+pub fn install_handlers() -> Result<()> {
+    unsafe { mujoco_sys::mju_user_warning = Some(warning_handler) };
+    if !UNWIND_SUPPORTED {
+        return Err(Error::Unsupported(
+            "unwinding MuJoCo errors requires the vendored-mujoco feature",
+        ));
+    }
+    // Safety: "C" and "C-unwind" share the same calling convention; the
+    // handler is only ever invoked from MuJoCo code built with unwind tables.
+    let error_handler: unsafe extern "C" fn(*const c_char) = unsafe {
+        std::mem::transmute::<
+            extern "C-unwind" fn(*const c_char),
+            unsafe extern "C" fn(*const c_char),
+        >(error_handler)
+    };
+    unsafe { mujoco_sys::mju_user_error = Some(error_handler) };
+    Ok(())
+}
+
+/// Restore MuJoCo's default handlers (print and exit on error)
+pub fn clear_handlers() {
+    // Keep the first guarded call from installing the handlers again
+    INIT.get_or_init(crate::version::check_version);
+    unsafe { mujoco_sys::mju_clearHandlers() }
+}
+
+fn init() -> Result<()> {
+    crate::version::check_version()?;
+    // Without unwind support MuJoCo's default error handler stays in place
+    let _ = install_handlers();
+    Ok(())
+}
+
+/// Run `f`, converting a MuJoCo error raised inside it into [`Error::Engine`]
+///
+/// Returns [`Error::VersionMismatch`] without running `f` if the linked library
+/// does not match the headers.
+pub(crate) fn guard<R>(f: impl FnOnce() -> R) -> Result<R> {
+    if let Err(Error::VersionMismatch { header, library }) = INIT.get_or_init(init) {
+        return Err(Error::VersionMismatch {
+            header: *header,
+            library: *library,
+        });
+    }
+    catch_unwind(AssertUnwindSafe(f)).map_err(|payload| match payload.downcast::<EngineError>() {
+        Ok(error) => Error::Engine(error.0),
+        Err(payload) => resume_unwind(payload),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "vendored-mujoco")]
+    fn test_engine_error_is_recoverable() {
+        let result = guard(|| unsafe { mujoco_sys::mju_error(c"test error %d".as_ptr(), 42) });
+        match result {
+            Err(Error::Engine(msg)) => assert!(msg.contains("test error 42")),
+            other => panic!("expected engine error, got {other:?}"),
+        }
+        assert_eq!(guard(|| 1).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_install_handlers_requires_unwind_support() {
+        assert_eq!(install_handlers().is_ok(), UNWIND_SUPPORTED);
+    }
+}
@@ -0,0 +1,349 @@
+//! Strongly typed element ids
+//!
+//! MuJoCo identifies elements by their index in the model arrays. The newtypes here
+//! keep ids of different element types apart, so passing a geom id where a body id
+//! is expected does not compile. Ids are checked against the model counts when they
+//! are constructed, and again by the wrappers that pass them to MuJoCo, since an id
+//! may come from a different model. With the `serde` feature, ids are deserialized
+//! through [`IdSeed`], which checks them against a model.
+
+use crate::Model;
+use crate::error::{Error, Result};
+use mujoco_sys::mjtObj;
+
+pub(crate) mod sealed {
+    /// Unchecked construction of ids, only available inside the crate
+    pub trait Sealed {
+        /// Wrap an index, which must come from the model itself
+        fn from_index(index: usize) -> Self;
+    }
+}
+
+/// Typed index of a model element
+///
+/// This trait is sealed: ids can only be created through checked constructors such
+/// as [`BodyId::new`] and [`BodyId::from_name`].
+pub trait ElementId: sealed::Sealed + Copy + Sized {
+    /// MuJoCo object type of the element
+    const OBJ: mjtObj;
+
+    /// Number of elements of this type in `model`
+    fn count(model: &Model) -> usize;
+
+    /// Index of the element in the model arrays
+    fn index(self) -> usize;
+}
+
+/// Anything that identifies an element of type `I`: its name or its typed id
+pub trait IntoId<I> {
+    /// Resolve to an id, checked against the elements of `model`
+    fn into_id(self, model: &Model) -> Result<I>;
+}
+
+impl<I: ElementId> IntoId<I> for &str {
+    fn into_id(self, model: &Model) -> Result<I> {
+        if self.contains('\0') {
+            return Err(Error::InvalidName(self.to_owned()));
+        }
+        crate::name2id(model, I::OBJ, self)
+            .map(|id| I::from_index(id as usize))
+            .ok_or_else(|| Error::NotFound(self.to_owned()))
+    }
+}
+
+/// Check a raw index against the number of elements of type `I`
+fn checked<I: ElementId>(model: &Model, index: usize) -> Result<I> {
+    let count = I::count(model);
+    if index >= count {
+        return Err(Error::InvalidId {
+            kind: I::OBJ,
+            id: index,
+            count,
+        });
+    }
+    Ok(I::from_index(index))
+}
+
+/// Convert an id that MuJoCo stores as `int`, where -1 means "none"
+pub(crate) fn optional<I: ElementId>(id: i32) -> Option<I> {
+    (id >= 0).then(|| I::from_index(id as usize))
+}
+
+macro_rules! element_id {
+    ($(#[$doc:meta])* $name:ident, $obj:ident, $count:ident) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize))]
+        pub struct $name(usize);
+
+        impl $name {
+            /// Id of the element with the given index, checked against the model
+            pub fn new(model: &Model, index: usize) -> Result<Self> {
+                checked(model, index)
+            }
+
+            /// Id of the element called `name`
+            pub fn from_name(model: &Model, name: &str) -> Result<Self> {
+                name.into_id(model)
+            }
+
+            /// Index of the element in the model arrays
+            pub fn index(self) -> usize {
+                self.0
+            }
+
+            /// Name of the element, `None` if it is unnamed
+            pub fn name(self, model: &Model) -> Option<&str> {
+                crate::id2name(model, mjtObj::$obj, self.0 as i32).filter(|name| !name.is_empty())
+            }
+        }
+
+        impl ElementId for $name {
+            const OBJ: mjtObj = mjtObj::$obj;
+
+            fn count(model: &Model) -> usize {
+                model.$count()
+            }
+
+            fn index(self) -> usize {
+                self.0
+            }
+        }
+
+        impl sealed::Sealed for $name {
+            fn from_index(index: usize) -> Self {
+                Self(index)
+            }
+        }
+
+        impl IntoId<$name> for $name {
+            fn into_id(self, model: &Model) -> Result<$name> {
+                checked(model, self.0)
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+    };
+}
+
+element_id!(
+    /// Id of a body
+    BodyId,
+    BODY,
+    nbody
+);
+element_id!(
+    /// Id of a joint
+    JointId,
+    JOINT,
+    njnt
+);
+element_id!(
+    /// Id of a geom
+    GeomId,
+    GEOM,
+    ngeom
+);
+element_id!(
+    /// Id of a site
+    SiteId,
+    SITE,
+    nsite
+);
+element_id!(
+    /// Id of a camera
+    CameraId,
+    CAMERA,
+    ncam
+);
+element_id!(
+    /// Id of a light
+    LightId,
+    LIGHT,
+    nlight
+);
+element_id!(
+    /// Id of an actuator
+    ActuatorId,
+    ACTUATOR,
+    nu
+);
+element_id!(
+    /// Id of a sensor
+    SensorId,
+    SENSOR,
+    nsensor
+);
+element_id!(
+    /// Id of a tendon
+    TendonId,
+    TENDON,
+    ntendon
+);
+element_id!(
+    /// Id of a keyframe
+    KeyframeId,
+    KEY,
+    nkey
+);
+
+/// Deserialize an id of type `I`, checked against `model`
+///
+/// Ids are serialized as their index. Deserializing one needs the model it belongs
+/// to, so there is no `Deserialize` impl; use this seed instead:
+/// `IdSeed::<BodyId>::new(&model).deserialize(deserializer)`.
+#[cfg(feature = "serde")]
+pub struct IdSeed<'m, I> {
+    model: &'m Model,
+    id: std::marker::PhantomData<I>,
+}
+
+#[cfg(feature = "serde")]
+impl<'m, I: ElementId> IdSeed<'m, I> {
+    /// Seed checking ids against the elements of `model`
+    pub fn new(model: &'m Model) -> Self {
+        Self {
+            model,
+            id: std::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, I: ElementId> serde::de::DeserializeSeed<'de> for IdSeed<'_, I> {
+    type Value = I;
+
+    fn deserialize<D: serde::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> std::result::Result<I, D::Error> {
+        let index = <usize as serde::Deserialize>::deserialize(deserializer)?;
+        checked(self.model, index).map_err(serde::de::Error::custom)
+    }
+}
+
+impl BodyId {
+    /// The world body, present in every model
+    pub const WORLD: BodyId = BodyId(0);
+}
+
+/// Frame of an element whose velocity or acceleration can be queried
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Object {
+    /// Body inertial frame, centered at the center of mass
+    Body(BodyId),
+    /// Regular body frame
+    XBody(BodyId),
+    /// Geom frame
+    Geom(GeomId),
+    /// Site frame
+    Site(SiteId),
+    /// Camera frame
+    Camera(CameraId),
+}
+
+impl Object {
+    /// MuJoCo object type and id
+    pub fn raw(self) -> (mjtObj, i32) {
+        match self {
+            Object::Body(id) => (mjtObj::BODY, id.0 as i32),
+            Object::XBody(id) => (mjtObj::XBODY, id.0 as i32),
+            Object::Geom(id) => (mjtObj::GEOM, id.0 as i32),
+            Object::Site(id) => (mjtObj::SITE, id.0 as i32),
+            Object::Camera(id) => (mjtObj::CAMERA, id.0 as i32),
+        }
+    }
+}
+
+impl Object {
+    /// MuJoCo object type and id, checked against the elements of `model`
+    pub(crate) fn checked(self, model: &Model) -> Result<(mjtObj, i32)> {
+        match self {
+            Object::Body(id) | Object::XBody(id) => checked::<BodyId>(model, id.0).map(drop),
+            Object::Geom(id) => checked::<GeomId>(model, id.0).map(drop),
+            Object::Site(id) => checked::<SiteId>(model, id.0).map(drop),
+            Object::Camera(id) => checked::<CameraId>(model, id.0).map(drop),
+        }?;
+        Ok(self.raw())
+    }
+}
+
+impl From<BodyId> for Object {
+    fn from(id: BodyId) -> Self {
+        Object::Body(id)
+    }
+}
+
+impl From<GeomId> for Object {
+    fn from(id: GeomId) -> Self {
+        Object::Geom(id)
+    }
+}
+
+impl From<SiteId> for Object {
+    fn from(id: SiteId) -> Self {
+        Object::Site(id)
+    }
+}
+
+impl From<CameraId> for Object {
+    fn from(id: CameraId) -> Self {
+        Object::Camera(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_ids() {
+        let model = Model::from_file(crate::tests::test_xml_path()).unwrap();
+        let link1 = BodyId::from_name(&model, "link1").unwrap();
+        assert_eq!(link1.index(), 2);
+        assert_eq!(link1.name(&model), Some("link1"));
+        assert_eq!(BodyId::new(&model, 2).unwrap(), link1);
+        assert_eq!(BodyId::WORLD.name(&model), Some("world"));
+
+        assert!(matches!(
+            BodyId::new(&model, model.nbody()),
+            Err(Error::InvalidId { count: 6, .. })
+        ));
+        assert!(matches!(
+            GeomId::from_name(&model, "link1"),
+            Err(Error::NotFound(_))
+        ));
+        // Ids are validated again when used with another model
+        let geom = GeomId::new(&model, 4).unwrap();
+        let empty = crate::Spec::new().compile().unwrap();
+        assert!(IntoId::<GeomId>::into_id(geom, &empty).is_err());
+        // and by the wrappers, instead of being passed on to MuJoCo
+        let data = crate::Data::new(&empty);
+        assert!(matches!(
+            crate::jac_geom(&data, geom),
+            Err(Error::InvalidId { count: 0, .. })
+        ));
+        assert!(crate::object_velocity(&data, link1, false).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_checked_ids() {
+        use serde::de::DeserializeSeed;
+
+        let model = Model::from_file(crate::tests::test_xml_path()).unwrap();
+        let link1 = BodyId::from_name(&model, "link1").unwrap();
+        let json = serde_json::to_string(&link1).unwrap();
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+        let id = IdSeed::<BodyId>::new(&model).deserialize(&mut deserializer);
+        assert_eq!(id.unwrap(), link1);
+
+        let mut deserializer = serde_json::Deserializer::from_str("6");
+        let id = IdSeed::<BodyId>::new(&model).deserialize(&mut deserializer);
+        assert!(id.unwrap_err().to_string().contains("out of range"));
+    }
+}
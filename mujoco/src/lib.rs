@@ -1,19 +1,68 @@
 /// Re-exporting the underlying unsafe API, should you need it
 pub use mujoco_sys as sys;
 
+pub mod checkpoint;
+pub mod constraint;
+pub mod contact;
 pub mod data;
 pub mod data_functions;
 pub mod data_struct;
+pub mod data_views;
+pub mod derivatives;
+pub mod error;
+pub mod flags;
+pub mod handlers;
+pub mod ids;
+pub mod mass_matrix;
 pub mod math;
 pub mod model;
 pub mod model_struct;
+pub mod model_views;
+pub mod options;
+#[cfg(feature = "rayon")]
+pub mod rollout;
 pub mod spec;
+pub mod spec_elements;
+pub mod state;
+pub mod thread_pool;
+pub mod version;
+pub mod vfs;
 
-pub use data::Data;
+pub use checkpoint::{Checkpoint, ModelFingerprint};
+#[cfg(feature = "sparse")]
+pub use constraint::constraint_jacobian_csr;
+pub use constraint::{constraint_jacobian, is_sparse, mul_jac_t_vec, mul_jac_vec};
+pub use contact::Contact;
+pub use data::{Data, DetachedData};
 pub use data_functions::*;
+pub use data_views::{DataActuator, DataBody, DataJoint, DataSensor};
+pub use derivatives::{InverseDerivatives, TransitionDerivatives, inverse_fd, transition_fd};
+pub use error::{Error, ErrorInfo, Result};
+pub use flags::{DisableFlags, EnableFlags};
+pub use handlers::{clear_handlers, install_handlers};
+#[cfg(feature = "serde")]
+pub use ids::IdSeed;
+pub use ids::{
+    ActuatorId, BodyId, CameraId, ElementId, GeomId, IntoId, JointId, KeyframeId, LightId, Object,
+    SensorId, SiteId, TendonId,
+};
+pub use mass_matrix::{add_m, full_mass_matrix, mul_m, mul_m2, solve_m, solve_m2};
 pub use math::*;
 pub use model::{Model, joint_nq, joint_nv};
-pub use spec::{ParseError, Spec};
+pub use model_views::{
+    ModelActuator, ModelBody, ModelCamera, ModelGeom, ModelJoint, ModelKeyframe, ModelSensor,
+    ModelSite, ModelTendon,
+};
+pub use options::{Cone, Integrator, Jacobian, Options, Solver};
+pub use spec::Spec;
+pub use spec_elements::{
+    ActuatorSpec, AttachPoint, BodySpec, CameraSpec, Element, FrameSpec, GeomSpec, JointSpec,
+    KeyframeSpec, LightSpec, SensorSpec, SiteSpec,
+};
+pub use state::{State, StateFlags, copy_state, state_size};
+pub use thread_pool::ThreadPool;
+pub use version::check_version;
+pub use vfs::Vfs;
 
 #[cfg(test)]
 mod tests {
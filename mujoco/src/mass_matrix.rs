@@ -0,0 +1,174 @@
+//! Operations on the joint-space inertia matrix `M`
+//!
+//! MuJoCo stores `M` (`qM`) and its `L'DL` factorization (`qLD`) in a custom sparse
+//! layout. These wrappers hide it behind nalgebra types. All of them use the
+//! matrices computed by the last `mj_forward` (or at least `mj_fwdPosition`).
+
+use crate::error::{Error, Result};
+use crate::{DMatrix, DVector, Data};
+
+fn check_nv(data: &Data, what: &str, len: usize) -> Result<()> {
+    let nv = data.model().nv();
+    if len != nv {
+        return Err(Error::InvalidBuffer(format!(
+            "{what} has {len} rows, expected nv = {nv}"
+        )));
+    }
+    Ok(())
+}
+
+/// Dense `nv`×`nv` inertia matrix
+pub fn full_mass_matrix(data: &Data) -> Result<DMatrix<f64>> {
+    let nv = data.model().nv();
+    // M is symmetric, so MuJoCo's row-major output needs no transpose
+    let mut mass = DMatrix::zeros(nv, nv);
+    crate::handlers::guard(|| unsafe {
+        mujoco_sys::mj_fullM(data.model.as_ptr(), mass.as_mut_ptr(), (*data.ptr).qM)
+    })?;
+    Ok(mass)
+}
+
+/// Multiply a vector by the inertia matrix: `M * vec`
+///
+/// Returns [`Error::InvalidBuffer`] if `vec` does not have `nv` entries.
+pub fn mul_m(data: &Data, vec: &DVector<f64>) -> Result<DVector<f64>> {
+    check_nv(data, "vec", vec.len())?;
+    let mut res = DVector::zeros(vec.len());
+    crate::handlers::guard(|| unsafe {
+        mujoco_sys::mj_mulM(
+            data.model.as_ptr(),
+            data.ptr,
+            res.as_mut_ptr(),
+            vec.as_ptr(),
+        )
+    })?;
+    Ok(res)
+}
+
+/// Multiply a vector by the square root of the inertia matrix: `sqrt(D) * L * vec`
+///
+/// The squared norm of the result is the quadratic form `vec' * M * vec`.
+///
+/// Returns [`Error::InvalidBuffer`] if `vec` does not have `nv` entries.
+pub fn mul_m2(data: &Data, vec: &DVector<f64>) -> Result<DVector<f64>> {
+    check_nv(data, "vec", vec.len())?;
+    let mut res = DVector::zeros(vec.len());
+    crate::handlers::guard(|| unsafe {
+        mujoco_sys::mj_mulM2(
+            data.model.as_ptr(),
+            data.ptr,
+            res.as_mut_ptr(),
+            vec.as_ptr(),
+        )
+    })?;
+    Ok(res)
+}
+
+/// Solve `M * x = y` for every column of `y` (an `nv`×`n` matrix)
+///
+/// Returns [`Error::InvalidBuffer`] if `y` does not have `nv` rows.
+pub fn solve_m(data: &mut Data, y: &DMatrix<f64>) -> Result<DMatrix<f64>> {
+    check_nv(data, "y", y.nrows())?;
+    // Each column is contiguous, i.e. the row-major n×nv layout MuJoCo expects
+    let mut x = DMatrix::zeros(y.nrows(), y.ncols());
+    crate::handlers::guard(|| unsafe {
+        mujoco_sys::mj_solveM(
+            data.model.as_ptr(),
+            data.ptr,
+            x.as_mut_ptr(),
+            y.as_ptr(),
+            y.ncols() as i32,
+        )
+    })?;
+    Ok(x)
+}
+
+/// Half of the solve of `M * x = y`: `x = inv(sqrt(D)) * inv(L') * y`, for every column of `y`
+///
+/// The squared norm of each result column is `y' * inv(M) * y`.
+///
+/// Returns [`Error::InvalidBuffer`] if `y` does not have `nv` rows.
+pub fn solve_m2(data: &mut Data, y: &DMatrix<f64>) -> Result<DMatrix<f64>> {
+    check_nv(data, "y", y.nrows())?;
+    let sqrt_inv_d: Vec<f64> = data.qLDiagInv().iter().map(|d| d.sqrt()).collect();
+    let mut x = DMatrix::zeros(y.nrows(), y.ncols());
+    crate::handlers::guard(|| unsafe {
+        mujoco_sys::mj_solveM2(
+            data.model.as_ptr(),
+            data.ptr,
+            x.as_mut_ptr(),
+            y.as_ptr(),
+            sqrt_inv_d.as_ptr(),
+            y.ncols() as i32,
+        )
+    })?;
+    Ok(x)
+}
+
+/// Add the inertia matrix to a dense `nv`×`nv` matrix: `dst += M`
+///
+/// Returns [`Error::InvalidBuffer`] if `dst` is not `nv`×`nv`.
+pub fn add_m(data: &mut Data, dst: &mut DMatrix<f64>) -> Result<()> {
+    check_nv(data, "dst", dst.nrows())?;
+    check_nv(data, "dst", dst.ncols())?;
+    // Null sparsity pointers select the dense destination layout
+    crate::handlers::guard(|| unsafe {
+        mujoco_sys::mj_addM(
+            data.model.as_ptr(),
+            data.ptr,
+            dst.as_mut_ptr(),
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup(model: &crate::Model) -> Data<'_> {
+        let mut data = Data::new(model);
+        data.qpos_mut().copy_from_slice(&[0.3, -0.2, 0.4]);
+        crate::forward(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_mass_matrix_products() {
+        let model = crate::Model::from_file(crate::tests::test_xml_path()).unwrap();
+        let mut data = setup(&model);
+        let mass = full_mass_matrix(&data).unwrap();
+        assert_eq!(mass.shape(), (model.nv(), model.nv()));
+        assert!((&mass - mass.transpose()).amax() < 1e-12);
+
+        let vec = DVector::from_vec(vec![1.0, -2.0, 0.5]);
+        let product = mul_m(&data, &vec).unwrap();
+        assert!((&product - &mass * &vec).amax() < 1e-10);
+        assert!((mul_m2(&data, &vec).unwrap().norm_squared() - vec.dot(&product)).abs() < 1e-10);
+
+        // Solving undoes the product, column by column
+        let y = DMatrix::from_columns(&[product.clone(), vec.clone()]);
+        let x = solve_m(&mut data, &y).unwrap();
+        assert!((x.column(0) - &vec).amax() < 1e-10);
+        assert!((&mass * x.column(1) - &vec).amax() < 1e-10);
+
+        let half = solve_m2(&mut data, &DMatrix::from_column_slice(3, 1, vec.as_slice())).unwrap();
+        assert!((half.norm_squared() - vec.dot(&x.column(1))).abs() < 1e-10);
+
+        let mut dst = DMatrix::identity(model.nv(), model.nv());
+        add_m(&mut data, &mut dst).unwrap();
+        assert!((dst - DMatrix::identity(model.nv(), model.nv()) - &mass).amax() < 1e-12);
+    }
+
+    #[test]
+    fn test_mul_m_wrong_size() {
+        let model = crate::Model::from_file(crate::tests::test_xml_path()).unwrap();
+        let data = setup(&model);
+        assert!(matches!(
+            mul_m(&data, &DVector::zeros(model.nv() + 1)),
+            Err(Error::InvalidBuffer(_))
+        ));
+    }
+}
@@ -1,45 +1,140 @@
-use std::ffi::CStr;
-use std::ffi::CString;
+use crate::error::{Error, Result};
 use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 /// Safe Rust wrapper around MuJoCo's mjModel
 #[derive(Debug)]
 pub struct Model {
     pub(crate) ptr: *mut mujoco_sys::mjModel,
+    /// Unique for the lifetime of the process, unlike the address of the mjModel
+    pub(crate) id: u64,
 }
 
+static NEXT_MODEL_ID: AtomicU64 = AtomicU64::new(0);
+
 // Safety: mjModel is thread-safe for read operations
 unsafe impl Send for Model {}
 unsafe impl Sync for Model {}
 
 impl Model {
+    /// Take ownership of a freshly allocated mjModel
+    ///
+    /// Returns [`Error::InvalidBuffer`] (and frees the model) if one of its enum arrays
+    /// holds an unknown code, which can only come from a corrupted MJB file.
+    pub(crate) fn from_raw(ptr: *mut mujoco_sys::mjModel) -> Result<Self> {
+        let model = Self {
+            ptr,
+            id: NEXT_MODEL_ID.fetch_add(1, Ordering::Relaxed),
+        };
+        model.check_enum_fields()?;
+        Ok(model)
+    }
+
+    /// Give the model a new id after its layout changed, e.g. by a recompile, so
+    /// data created for the old layout no longer attaches to it
+    pub(crate) fn renew_id(&mut self) {
+        self.id = NEXT_MODEL_ID.fetch_add(1, Ordering::Relaxed);
+    }
+
     /// Load a MuJoCo model from an XML file
-    pub fn from_file(filename: impl AsRef<Path>) -> Result<Self, String> {
-        let c_filename = CString::new(filename.as_ref().to_string_lossy().as_ref())
-            .map_err(|e| format!("Failed to convert filename to CString: {}", e))?;
+    pub fn from_file(filename: impl AsRef<Path>) -> Result<Self> {
+        Self::load_xml(filename.as_ref(), None)
+    }
 
-        const ERROR_SIZE: usize = 1000;
-        let mut error_buf = [0u8; ERROR_SIZE];
+    /// Load a MuJoCo model from an XML file, resolving the file and its assets
+    /// (meshes, textures, included files) through the given VFS first
+    pub fn from_xml_with_vfs(filename: impl AsRef<Path>, vfs: &crate::Vfs) -> Result<Self> {
+        Self::load_xml(filename.as_ref(), Some(vfs))
+    }
+
+    fn load_xml(filename: &Path, vfs: Option<&crate::Vfs>) -> Result<Self> {
+        // Parse and compile separately (what mj_loadXML does internally) so that
+        // compiler errors are reported as such
+        let spec = crate::Spec::parse_file(filename, vfs)?;
+        spec.compile_vfs(vfs).map_err(|e| match e {
+            Error::Compile(mut info) => {
+                info.file = Some(filename.to_path_buf());
+                Error::Compile(info)
+            }
+            other => other,
+        })
+    }
+
+    /// Load a model from a binary MJB file written by [`Model::save_mjb`]
+    pub fn from_mjb(filename: impl AsRef<Path>) -> Result<Self> {
+        let buffer = std::fs::read(filename)?;
+        Self::from_bytes(&buffer)
+    }
 
+    /// Load a model from an in-memory MJB buffer produced by [`Model::to_bytes`]
+    ///
+    /// Returns [`Error::InvalidBuffer`] if the buffer is not a valid MJB file for this
+    /// MuJoCo version, or if one of the model's enum arrays holds an unknown code.
+    pub fn from_bytes(buffer: &[u8]) -> Result<Self> {
+        let size = std::os::raw::c_int::try_from(buffer.len()).map_err(|_| {
+            Error::InvalidBuffer(format!("{} bytes exceeds the MJB size limit", buffer.len()))
+        })?;
         let ptr = unsafe {
-            mujoco_sys::mj_loadXML(
-                c_filename.as_ptr(),
+            mujoco_sys::mj_loadModelBuffer(buffer.as_ptr() as *const std::os::raw::c_void, size)
+        };
+        if ptr.is_null() {
+            return Err(Error::InvalidBuffer(
+                "bad header, version mismatch or truncated MJB data".into(),
+            ));
+        }
+        Self::from_raw(ptr)
+    }
+
+    /// Serialize the model into an MJB buffer
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let size = unsafe { mujoco_sys::mj_sizeModel(self.ptr) } as usize;
+        let c_size = std::os::raw::c_int::try_from(size).map_err(|_| {
+            Error::InvalidBuffer(format!("{size} bytes exceeds the MJB size limit"))
+        })?;
+        let mut buffer = vec![0u8; size];
+        crate::handlers::guard(|| unsafe {
+            mujoco_sys::mj_saveModel(
+                self.ptr,
                 std::ptr::null(),
-                error_buf.as_mut_ptr() as *mut std::os::raw::c_char,
-                ERROR_SIZE as std::os::raw::c_int,
+                buffer.as_mut_ptr() as *mut std::os::raw::c_void,
+                c_size,
             )
-        };
+        })?;
+        Ok(buffer)
+    }
 
+    /// Save the model to a binary MJB file
+    pub fn save_mjb(&self, filename: impl AsRef<Path>) -> Result<()> {
+        std::fs::write(filename, self.to_bytes()?)?;
+        Ok(())
+    }
+
+    /// Copy the model, returning an error instead of panicking if allocation fails
+    pub fn try_clone(&self) -> Result<Self> {
+        let ptr = crate::handlers::guard(|| unsafe {
+            mujoco_sys::mj_copyModel(std::ptr::null_mut(), self.ptr)
+        })?;
         if ptr.is_null() {
-            // Extract error message
-            let error_msg = unsafe {
-                CStr::from_ptr(error_buf.as_ptr() as *const std::os::raw::c_char)
-                    .to_string_lossy()
-                    .into_owned()
-            };
-            return Err(error_msg);
+            return Err(Error::Allocation("mjModel"));
         }
-        Ok(Self { ptr })
+        Self::from_raw(ptr)
+    }
+
+    /// Store the state of `data` in the keyframe `key`, given its name or id
+    ///
+    /// The keyframe is written into the model, so the data has to be detached from it
+    /// first with [`crate::Data::detach`].
+    ///
+    /// Returns [`Error::ModelMismatch`] if `data` was created for a different model.
+    pub fn set_keyframe(
+        &mut self,
+        key: impl crate::IntoId<crate::KeyframeId>,
+        data: &crate::DetachedData,
+    ) -> Result<()> {
+        data.check_model(self)?;
+        let key = key.into_id(self)?;
+        unsafe { mujoco_sys::mj_setKeyframe(self.ptr, data.ptr, key.index() as i32) };
+        Ok(())
     }
 
     /// Get the raw pointer (for FFI calls)
@@ -63,6 +158,23 @@ impl Model {
     }
 }
 
+/// Check the codes of an `int` array of `mjModel` holding values of the enum `E`,
+/// and below `limit` if only part of the enum is valid in a model
+pub(crate) fn check_enum_codes<E: TryFrom<i32>>(
+    codes: *const i32,
+    len: usize,
+    field: &str,
+    limit: Option<i32>,
+) -> Result<()> {
+    let codes = unsafe { std::slice::from_raw_parts(codes, len) };
+    let invalid =
+        |code: i32| E::try_from(code).is_err() || limit.is_some_and(|limit| code >= limit);
+    match codes.iter().find(|&&code| invalid(code)) {
+        Some(code) => Err(Error::InvalidBuffer(format!("invalid {field} code {code}"))),
+        None => Ok(()),
+    }
+}
+
 /// Get number of generalized coordinates for a given joint type
 pub fn joint_nq(joint_type: mujoco_sys::mjtJoint) -> usize {
     match joint_type {
@@ -96,12 +208,7 @@ impl Drop for Model {
 
 impl Clone for Model {
     fn clone(&self) -> Self {
-        let ptr = unsafe { mujoco_sys::mj_copyModel(std::ptr::null_mut(), self.ptr) };
-        assert!(
-            !ptr.is_null(),
-            "mj_copyModel failed: allocation returned null"
-        );
-        Self { ptr }
+        self.try_clone().expect("mj_copyModel failed")
     }
 }
 
@@ -119,9 +226,107 @@ mod tests {
         assert!(model.nu() == 3);
     }
 
+    #[test]
+    fn test_enum_fields_and_option_flags() {
+        let mut model = Model::from_file(crate::tests::test_xml_path()).unwrap();
+        assert_eq!(model.jnt_type(), &[mujoco_sys::mjtJoint::HINGE; 3]);
+        assert_eq!(model.actuator_dyntype(), &[mujoco_sys::mjtDyn::NONE; 3]);
+        assert_eq!(model.actuator_biastype(), &[mujoco_sys::mjtBias::AFFINE; 3]);
+        model.actuator_biastype_mut()[0] = mujoco_sys::mjtBias::NONE;
+        assert_eq!(model.actuator_biastype()[0], mujoco_sys::mjtBias::NONE);
+        assert!(model.disable_flags().is_empty());
+
+        model.set_disable_flags(crate::DisableFlags::GRAVITY | crate::DisableFlags::CONTACT);
+        assert_eq!(model.opt().disableflags, 128 | 16);
+        assert!(model.disable_flags().contains(crate::DisableFlags::GRAVITY));
+        model.set_enable_flags(crate::EnableFlags::ENERGY);
+        assert_eq!(model.enable_flags(), crate::EnableFlags::ENERGY);
+
+        // Unknown bits never surface in the typed flags
+        model.raw_mut().opt.enableflags |= 1 << 30;
+        assert_eq!(model.enable_flags(), crate::EnableFlags::ENERGY);
+    }
+
+    #[test]
+    fn test_mjb_with_invalid_enum_code() {
+        let mut model = Model::from_file(crate::tests::test_xml_path()).unwrap();
+        // Corrupt the raw codes, as a damaged or hand-edited MJB file would
+        unsafe { *(*model.as_mut_ptr()).jnt_type.add(1) = 42 };
+        let bytes = model.to_bytes().unwrap();
+        assert!(matches!(
+            Model::from_bytes(&bytes),
+            Err(Error::InvalidBuffer(message)) if message.contains("jnt_type code 42")
+        ));
+    }
+
+    #[test]
+    fn test_mjb_with_non_model_geom_type() {
+        // The enum count and the render-only types are not valid in a model
+        for code in [9, 100] {
+            assert_eq!(mujoco_sys::mjtGeom::try_from(code).is_ok(), code == 100);
+            let mut model = Model::from_file(crate::tests::test_xml_path()).unwrap();
+            unsafe { *(*model.as_mut_ptr()).geom_type = code };
+            let bytes = model.to_bytes().unwrap();
+            assert!(matches!(
+                Model::from_bytes(&bytes),
+                Err(Error::InvalidBuffer(message)) if message.contains(&format!("geom_type code {code}"))
+            ));
+        }
+    }
+
     #[test]
     fn test_model_from_invalid_xml_file() {
         let model = Model::from_file(crate::tests::test_malformed_xml_path());
-        assert!(model.is_err());
+        match model {
+            Err(Error::Parse(info)) => {
+                assert_eq!(info.file, Some(crate::tests::test_malformed_xml_path()));
+                assert_eq!(info.line, Some(4));
+            }
+            other => panic!("expected parse error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_model_from_uncompilable_xml_file() {
+        let path = std::env::temp_dir().join(format!("mujoco-rs-{}-bad.xml", std::process::id()));
+        std::fs::write(
+            &path,
+            r#"<mujoco><worldbody><body><geom type="mesh" mesh="missing"/></body></worldbody></mujoco>"#,
+        )
+        .unwrap();
+        let model = Model::from_file(&path);
+        std::fs::remove_file(&path).unwrap();
+        match model {
+            Err(Error::Compile(info)) => assert_eq!(info.file, Some(path)),
+            other => panic!("expected compile error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_model_mjb_roundtrip() {
+        let model = Model::from_file(crate::tests::test_xml_path()).unwrap();
+        let bytes = model.to_bytes().unwrap();
+        let loaded = Model::from_bytes(&bytes).unwrap();
+        assert_eq!(loaded.nq(), model.nq());
+        assert_eq!(loaded.nu(), model.nu());
+        assert_eq!(loaded.body_mass(), model.body_mass());
+
+        let path = std::env::temp_dir().join(format!("mujoco-rs-{}.mjb", std::process::id()));
+        model.save_mjb(&path).unwrap();
+        let loaded = Model::from_mjb(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.unwrap().nbody(), model.nbody());
+    }
+
+    #[test]
+    fn test_model_from_invalid_bytes() {
+        assert!(matches!(
+            Model::from_bytes(b"not an mjb file"),
+            Err(Error::InvalidBuffer(_))
+        ));
+        assert!(matches!(
+            Model::from_mjb("/nonexistent/model.mjb"),
+            Err(Error::Io(_))
+        ));
     }
 }
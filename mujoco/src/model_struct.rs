@@ -2,7 +2,11 @@
 //! Generated by generate_from_introspect.py - DO NOT EDIT MANUALLY
 
 use crate::Model;
-use mujoco_sys::{mjOption, mjStatistic, mjVisual, mjtNum};
+use mujoco_sys::{
+    mjOption, mjStatistic, mjVisual, mjtBias, mjtCamLight, mjtColorSpace, mjtDataType, mjtDyn,
+    mjtEq, mjtFlexSelf, mjtGain, mjtGeom, mjtJoint, mjtLightType, mjtNum, mjtObj, mjtProjection,
+    mjtSensor, mjtSleepPolicy, mjtStage, mjtTexture, mjtTrn, mjtWrap,
+};
 
 #[allow(non_snake_case, clippy::unnecessary_cast)]
 impl Model {
@@ -481,8 +485,8 @@ impl Model {
     }
 
     /// type of joint (mjtJoint)
-    pub fn jnt_type(&self) -> &[i32] {
-        unsafe { std::slice::from_raw_parts(self.raw().jnt_type, self.njnt()) }
+    pub fn jnt_type(&self) -> &[mjtJoint] {
+        unsafe { std::slice::from_raw_parts(self.raw().jnt_type as *const mjtJoint, self.njnt()) }
     }
 
     /// start addr in 'qpos' for joint's data
@@ -676,13 +680,18 @@ impl Model {
     }
 
     /// sleep policy (mjtSleepPolicy)
-    pub fn tree_sleep_policy(&self) -> &[i32] {
-        unsafe { std::slice::from_raw_parts(self.raw().tree_sleep_policy, self.ntree()) }
+    pub fn tree_sleep_policy(&self) -> &[mjtSleepPolicy] {
+        unsafe {
+            std::slice::from_raw_parts(
+                self.raw().tree_sleep_policy as *const mjtSleepPolicy,
+                self.ntree(),
+            )
+        }
     }
 
     /// geometric type (mjtGeom)
-    pub fn geom_type(&self) -> &[i32] {
-        unsafe { std::slice::from_raw_parts(self.raw().geom_type, self.ngeom()) }
+    pub fn geom_type(&self) -> &[mjtGeom] {
+        unsafe { std::slice::from_raw_parts(self.raw().geom_type as *const mjtGeom, self.ngeom()) }
     }
 
     /// geom contact type
@@ -823,8 +832,8 @@ impl Model {
     }
 
     /// geom type for rendering (mjtGeom)
-    pub fn site_type(&self) -> &[i32] {
-        unsafe { std::slice::from_raw_parts(self.raw().site_type, self.nsite()) }
+    pub fn site_type(&self) -> &[mjtGeom] {
+        unsafe { std::slice::from_raw_parts(self.raw().site_type as *const mjtGeom, self.nsite()) }
     }
 
     /// id of site's body
@@ -875,8 +884,10 @@ impl Model {
     }
 
     /// camera tracking mode (mjtCamLight)
-    pub fn cam_mode(&self) -> &[i32] {
-        unsafe { std::slice::from_raw_parts(self.raw().cam_mode, self.ncam()) }
+    pub fn cam_mode(&self) -> &[mjtCamLight] {
+        unsafe {
+            std::slice::from_raw_parts(self.raw().cam_mode as *const mjtCamLight, self.ncam())
+        }
     }
 
     /// id of camera's body
@@ -915,8 +926,23 @@ impl Model {
     }
 
     /// projection type (mjtProjection)
-    pub fn cam_projection(&self) -> &[i32] {
-        unsafe { std::slice::from_raw_parts(self.raw().cam_projection, self.ncam()) }
+    pub fn cam_projection(&self) -> &[mjtProjection] {
+        unsafe {
+            std::slice::from_raw_parts(
+                self.raw().cam_projection as *const mjtProjection,
+                self.ncam(),
+            )
+        }
+    }
+
+    /// projection type (mjtProjection)
+    pub fn cam_projection_mut(&mut self) -> &mut [mjtProjection] {
+        unsafe {
+            std::slice::from_raw_parts_mut(
+                self.raw_mut().cam_projection as *mut mjtProjection,
+                self.ncam(),
+            )
+        }
     }
 
     /// y field-of-view (ortho ? len : deg)
@@ -955,8 +981,10 @@ impl Model {
     }
 
     /// light tracking mode (mjtCamLight)
-    pub fn light_mode(&self) -> &[i32] {
-        unsafe { std::slice::from_raw_parts(self.raw().light_mode, self.nlight()) }
+    pub fn light_mode(&self) -> &[mjtCamLight] {
+        unsafe {
+            std::slice::from_raw_parts(self.raw().light_mode as *const mjtCamLight, self.nlight())
+        }
     }
 
     /// id of light's body
@@ -970,8 +998,20 @@ impl Model {
     }
 
     /// spot, directional, etc. (mjtLightType)
-    pub fn light_type(&self) -> &[i32] {
-        unsafe { std::slice::from_raw_parts(self.raw().light_type, self.nlight()) }
+    pub fn light_type(&self) -> &[mjtLightType] {
+        unsafe {
+            std::slice::from_raw_parts(self.raw().light_type as *const mjtLightType, self.nlight())
+        }
+    }
+
+    /// spot, directional, etc. (mjtLightType)
+    pub fn light_type_mut(&mut self) -> &mut [mjtLightType] {
+        unsafe {
+            std::slice::from_raw_parts_mut(
+                self.raw_mut().light_type as *mut mjtLightType,
+                self.nlight(),
+            )
+        }
     }
 
     /// texture id for image lights
@@ -1125,8 +1165,13 @@ impl Model {
     }
 
     /// self collision mode (mjtFlexSelf)
-    pub fn flex_selfcollide(&self) -> &[i32] {
-        unsafe { std::slice::from_raw_parts(self.raw().flex_selfcollide, self.nflex()) }
+    pub fn flex_selfcollide(&self) -> &[mjtFlexSelf] {
+        unsafe {
+            std::slice::from_raw_parts(
+                self.raw().flex_selfcollide as *const mjtFlexSelf,
+                self.nflex(),
+            )
+        }
     }
 
     /// number of active element layers, 3D only
@@ -1750,13 +1795,18 @@ impl Model {
     }
 
     /// texture type (mjtTexture)
-    pub fn tex_type(&self) -> &[i32] {
-        unsafe { std::slice::from_raw_parts(self.raw().tex_type, self.ntex()) }
+    pub fn tex_type(&self) -> &[mjtTexture] {
+        unsafe { std::slice::from_raw_parts(self.raw().tex_type as *const mjtTexture, self.ntex()) }
     }
 
     /// texture colorspace (mjtColorSpace)
-    pub fn tex_colorspace(&self) -> &[i32] {
-        unsafe { std::slice::from_raw_parts(self.raw().tex_colorspace, self.ntex()) }
+    pub fn tex_colorspace(&self) -> &[mjtColorSpace] {
+        unsafe {
+            std::slice::from_raw_parts(
+                self.raw().tex_colorspace as *const mjtColorSpace,
+                self.ntex(),
+            )
+        }
     }
 
     /// number of rows in texture image
@@ -1915,8 +1965,8 @@ impl Model {
     }
 
     /// constraint type (mjtEq)
-    pub fn eq_type(&self) -> &[i32] {
-        unsafe { std::slice::from_raw_parts(self.raw().eq_type, self.neq()) }
+    pub fn eq_type(&self) -> &[mjtEq] {
+        unsafe { std::slice::from_raw_parts(self.raw().eq_type as *const mjtEq, self.neq()) }
     }
 
     /// id of object 1
@@ -1930,8 +1980,8 @@ impl Model {
     }
 
     /// type of both objects (mjtObj)
-    pub fn eq_objtype(&self) -> &[i32] {
-        unsafe { std::slice::from_raw_parts(self.raw().eq_objtype, self.neq()) }
+    pub fn eq_objtype(&self) -> &[mjtObj] {
+        unsafe { std::slice::from_raw_parts(self.raw().eq_objtype as *const mjtObj, self.neq()) }
     }
 
     /// initial enable/disable constraint state
@@ -2117,8 +2167,8 @@ impl Model {
     }
 
     /// wrap object type (mjtWrap)
-    pub fn wrap_type(&self) -> &[i32] {
-        unsafe { std::slice::from_raw_parts(self.raw().wrap_type, self.nwrap()) }
+    pub fn wrap_type(&self) -> &[mjtWrap] {
+        unsafe { std::slice::from_raw_parts(self.raw().wrap_type as *const mjtWrap, self.nwrap()) }
     }
 
     /// object id: geom, site, joint
@@ -2132,23 +2182,51 @@ impl Model {
     }
 
     /// transmission type (mjtTrn)
-    pub fn actuator_trntype(&self) -> &[i32] {
-        unsafe { std::slice::from_raw_parts(self.raw().actuator_trntype, self.nu()) }
+    pub fn actuator_trntype(&self) -> &[mjtTrn] {
+        unsafe {
+            std::slice::from_raw_parts(self.raw().actuator_trntype as *const mjtTrn, self.nu())
+        }
     }
 
     /// dynamics type (mjtDyn)
-    pub fn actuator_dyntype(&self) -> &[i32] {
-        unsafe { std::slice::from_raw_parts(self.raw().actuator_dyntype, self.nu()) }
+    pub fn actuator_dyntype(&self) -> &[mjtDyn] {
+        unsafe {
+            std::slice::from_raw_parts(self.raw().actuator_dyntype as *const mjtDyn, self.nu())
+        }
     }
 
     /// gain type (mjtGain)
-    pub fn actuator_gaintype(&self) -> &[i32] {
-        unsafe { std::slice::from_raw_parts(self.raw().actuator_gaintype, self.nu()) }
+    pub fn actuator_gaintype(&self) -> &[mjtGain] {
+        unsafe {
+            std::slice::from_raw_parts(self.raw().actuator_gaintype as *const mjtGain, self.nu())
+        }
+    }
+
+    /// gain type (mjtGain)
+    pub fn actuator_gaintype_mut(&mut self) -> &mut [mjtGain] {
+        unsafe {
+            std::slice::from_raw_parts_mut(
+                self.raw_mut().actuator_gaintype as *mut mjtGain,
+                self.nu(),
+            )
+        }
     }
 
     /// bias type (mjtBias)
-    pub fn actuator_biastype(&self) -> &[i32] {
-        unsafe { std::slice::from_raw_parts(self.raw().actuator_biastype, self.nu()) }
+    pub fn actuator_biastype(&self) -> &[mjtBias] {
+        unsafe {
+            std::slice::from_raw_parts(self.raw().actuator_biastype as *const mjtBias, self.nu())
+        }
+    }
+
+    /// bias type (mjtBias)
+    pub fn actuator_biastype_mut(&mut self) -> &mut [mjtBias] {
+        unsafe {
+            std::slice::from_raw_parts_mut(
+                self.raw_mut().actuator_biastype as *mut mjtBias,
+                self.nu(),
+            )
+        }
     }
 
     /// transmission id: joint, tendon, site
@@ -2289,23 +2367,37 @@ impl Model {
     }
 
     /// sensor type (mjtSensor)
-    pub fn sensor_type(&self) -> &[i32] {
-        unsafe { std::slice::from_raw_parts(self.raw().sensor_type, self.nsensor()) }
+    pub fn sensor_type(&self) -> &[mjtSensor] {
+        unsafe {
+            std::slice::from_raw_parts(self.raw().sensor_type as *const mjtSensor, self.nsensor())
+        }
     }
 
     /// numeric data type (mjtDataType)
-    pub fn sensor_datatype(&self) -> &[i32] {
-        unsafe { std::slice::from_raw_parts(self.raw().sensor_datatype, self.nsensor()) }
+    pub fn sensor_datatype(&self) -> &[mjtDataType] {
+        unsafe {
+            std::slice::from_raw_parts(
+                self.raw().sensor_datatype as *const mjtDataType,
+                self.nsensor(),
+            )
+        }
     }
 
     /// required compute stage (mjtStage)
-    pub fn sensor_needstage(&self) -> &[i32] {
-        unsafe { std::slice::from_raw_parts(self.raw().sensor_needstage, self.nsensor()) }
+    pub fn sensor_needstage(&self) -> &[mjtStage] {
+        unsafe {
+            std::slice::from_raw_parts(
+                self.raw().sensor_needstage as *const mjtStage,
+                self.nsensor(),
+            )
+        }
     }
 
     /// type of sensorized object (mjtObj)
-    pub fn sensor_objtype(&self) -> &[i32] {
-        unsafe { std::slice::from_raw_parts(self.raw().sensor_objtype, self.nsensor()) }
+    pub fn sensor_objtype(&self) -> &[mjtObj] {
+        unsafe {
+            std::slice::from_raw_parts(self.raw().sensor_objtype as *const mjtObj, self.nsensor())
+        }
     }
 
     /// id of sensorized object
@@ -2314,8 +2406,10 @@ impl Model {
     }
 
     /// type of reference frame (mjtObj)
-    pub fn sensor_reftype(&self) -> &[i32] {
-        unsafe { std::slice::from_raw_parts(self.raw().sensor_reftype, self.nsensor()) }
+    pub fn sensor_reftype(&self) -> &[mjtObj] {
+        unsafe {
+            std::slice::from_raw_parts(self.raw().sensor_reftype as *const mjtObj, self.nsensor())
+        }
     }
 
     /// id of reference frame; -1: global frame
@@ -2697,4 +2791,142 @@ impl Model {
     pub fn signature(&self) -> u64 {
         self.raw().signature as u64
     }
+
+    /// Check that the enum arrays only hold valid codes, before they are handed out as enums
+    pub(crate) fn check_enum_fields(&self) -> crate::Result<()> {
+        crate::model::check_enum_codes::<mjtSleepPolicy>(
+            self.raw().tree_sleep_policy,
+            self.ntree(),
+            "tree_sleep_policy",
+            None,
+        )?;
+        crate::model::check_enum_codes::<mjtJoint>(
+            self.raw().jnt_type,
+            self.njnt(),
+            "jnt_type",
+            None,
+        )?;
+        crate::model::check_enum_codes::<mjtGeom>(
+            self.raw().geom_type,
+            self.ngeom(),
+            "geom_type",
+            Some(mjtGeom::mjNGEOMTYPES as i32),
+        )?;
+        crate::model::check_enum_codes::<mjtGeom>(
+            self.raw().site_type,
+            self.nsite(),
+            "site_type",
+            Some(mjtGeom::mjNGEOMTYPES as i32),
+        )?;
+        crate::model::check_enum_codes::<mjtCamLight>(
+            self.raw().cam_mode,
+            self.ncam(),
+            "cam_mode",
+            None,
+        )?;
+        crate::model::check_enum_codes::<mjtProjection>(
+            self.raw().cam_projection,
+            self.ncam(),
+            "cam_projection",
+            None,
+        )?;
+        crate::model::check_enum_codes::<mjtCamLight>(
+            self.raw().light_mode,
+            self.nlight(),
+            "light_mode",
+            None,
+        )?;
+        crate::model::check_enum_codes::<mjtLightType>(
+            self.raw().light_type,
+            self.nlight(),
+            "light_type",
+            None,
+        )?;
+        crate::model::check_enum_codes::<mjtFlexSelf>(
+            self.raw().flex_selfcollide,
+            self.nflex(),
+            "flex_selfcollide",
+            None,
+        )?;
+        crate::model::check_enum_codes::<mjtTexture>(
+            self.raw().tex_type,
+            self.ntex(),
+            "tex_type",
+            None,
+        )?;
+        crate::model::check_enum_codes::<mjtColorSpace>(
+            self.raw().tex_colorspace,
+            self.ntex(),
+            "tex_colorspace",
+            None,
+        )?;
+        crate::model::check_enum_codes::<mjtEq>(self.raw().eq_type, self.neq(), "eq_type", None)?;
+        crate::model::check_enum_codes::<mjtObj>(
+            self.raw().eq_objtype,
+            self.neq(),
+            "eq_objtype",
+            Some(mjtObj::mjNOBJECT as i32),
+        )?;
+        crate::model::check_enum_codes::<mjtWrap>(
+            self.raw().wrap_type,
+            self.nwrap(),
+            "wrap_type",
+            None,
+        )?;
+        crate::model::check_enum_codes::<mjtTrn>(
+            self.raw().actuator_trntype,
+            self.nu(),
+            "actuator_trntype",
+            None,
+        )?;
+        crate::model::check_enum_codes::<mjtDyn>(
+            self.raw().actuator_dyntype,
+            self.nu(),
+            "actuator_dyntype",
+            None,
+        )?;
+        crate::model::check_enum_codes::<mjtGain>(
+            self.raw().actuator_gaintype,
+            self.nu(),
+            "actuator_gaintype",
+            None,
+        )?;
+        crate::model::check_enum_codes::<mjtBias>(
+            self.raw().actuator_biastype,
+            self.nu(),
+            "actuator_biastype",
+            None,
+        )?;
+        crate::model::check_enum_codes::<mjtSensor>(
+            self.raw().sensor_type,
+            self.nsensor(),
+            "sensor_type",
+            None,
+        )?;
+        crate::model::check_enum_codes::<mjtDataType>(
+            self.raw().sensor_datatype,
+            self.nsensor(),
+            "sensor_datatype",
+            None,
+        )?;
+        crate::model::check_enum_codes::<mjtStage>(
+            self.raw().sensor_needstage,
+            self.nsensor(),
+            "sensor_needstage",
+            None,
+        )?;
+        crate::model::check_enum_codes::<mjtObj>(
+            self.raw().sensor_objtype,
+            self.nsensor(),
+            "sensor_objtype",
+            Some(mjtObj::mjNOBJECT as i32),
+        )?;
+        crate::model::check_enum_codes::<mjtObj>(
+            self.raw().sensor_reftype,
+            self.nsensor(),
+            "sensor_reftype",
+            Some(mjtObj::mjNOBJECT as i32),
+        )?;
+        Ok(())
+    }
 }
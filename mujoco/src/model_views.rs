@@ -0,0 +1,738 @@
+//! Named, typed views of model elements, similar to `MjModel.body("name")` in Python
+//!
+//! Each view borrows the model and reads one element out of the flat `mjModel`
+//! arrays, converting vectors, quaternions and enum codes to their Rust types.
+
+use crate::error::Result;
+use crate::ids::sealed::Sealed;
+use crate::ids::{IntoId, optional};
+use crate::{
+    ActuatorId, BodyId, CameraId, GeomId, JointId, KeyframeId, Model, Quat, SensorId, SiteId,
+    TendonId, Vec3,
+};
+use mujoco_sys::{
+    mjtBias, mjtCamLight, mjtDyn, mjtGain, mjtGeom, mjtJoint, mjtObj, mjtProjection, mjtSensor,
+    mjtTrn,
+};
+use std::ops::Range;
+
+pub(crate) fn vec3(values: &[f64], id: usize) -> Vec3 {
+    Vec3::from_column_slice(&values[3 * id..3 * id + 3])
+}
+
+pub(crate) fn quat(values: &[f64], id: usize) -> Quat {
+    let q = &values[4 * id..4 * id + 4];
+    // MuJoCo uses (w, x, y, z), nalgebra Quaternion::new takes (w, x, y, z)
+    Quat::from_quaternion(nalgebra::Quaternion::new(q[0], q[1], q[2], q[3]))
+}
+
+fn pair(values: &[f64], id: usize) -> [f64; 2] {
+    [values[2 * id], values[2 * id + 1]]
+}
+
+macro_rules! model_view {
+    ($(#[$doc:meta])* $name:ident, $method:ident, $id:ident) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name<'m> {
+            model: &'m Model,
+            id: usize,
+        }
+
+        impl<'m> $name<'m> {
+            /// Id of the element
+            pub fn id(&self) -> $id {
+                $id::from_index(self.id)
+            }
+
+            /// Name of the element, empty if it is unnamed
+            pub fn name(&self) -> &'m str {
+                self.id().name(self.model).unwrap_or("")
+            }
+        }
+
+        impl Model {
+            #[doc = concat!("Typed view of a ", stringify!($method), ", given its name or id")]
+            pub fn $method(&self, element: impl IntoId<$id>) -> Result<$name<'_>> {
+                let id = element.into_id(self)?.index();
+                Ok($name { model: self, id })
+            }
+        }
+    };
+}
+
+model_view!(
+    /// Body of a [`Model`], see [`Model::body`]
+    ModelBody,
+    body,
+    BodyId
+);
+model_view!(
+    /// Joint of a [`Model`], see [`Model::joint`]
+    ModelJoint,
+    joint,
+    JointId
+);
+model_view!(
+    /// Geom of a [`Model`], see [`Model::geom`]
+    ModelGeom,
+    geom,
+    GeomId
+);
+model_view!(
+    /// Site of a [`Model`], see [`Model::site`]
+    ModelSite,
+    site,
+    SiteId
+);
+model_view!(
+    /// Actuator of a [`Model`], see [`Model::actuator`]
+    ModelActuator,
+    actuator,
+    ActuatorId
+);
+model_view!(
+    /// Sensor of a [`Model`], see [`Model::sensor`]
+    ModelSensor,
+    sensor,
+    SensorId
+);
+model_view!(
+    /// Camera of a [`Model`], see [`Model::camera`]
+    ModelCamera,
+    camera,
+    CameraId
+);
+model_view!(
+    /// Tendon of a [`Model`], see [`Model::tendon`]
+    ModelTendon,
+    tendon,
+    TendonId
+);
+model_view!(
+    /// Keyframe of a [`Model`], see [`Model::keyframe`]
+    ModelKeyframe,
+    keyframe,
+    KeyframeId
+);
+
+impl ModelBody<'_> {
+    /// Id of the parent body (the world body is its own parent)
+    pub fn parent_id(&self) -> BodyId {
+        BodyId::from_index(self.model.body_parentid()[self.id] as usize)
+    }
+
+    /// Id of the root of the kinematic tree containing this body
+    pub fn root_id(&self) -> BodyId {
+        BodyId::from_index(self.model.body_rootid()[self.id] as usize)
+    }
+
+    /// Index into `mocap_pos`/`mocap_quat`, if this is a mocap body
+    pub fn mocap_id(&self) -> Option<usize> {
+        let id = self.model.body_mocapid()[self.id];
+        (id >= 0).then_some(id as usize)
+    }
+
+    /// Position offset relative to the parent body
+    pub fn pos(&self) -> Vec3 {
+        vec3(self.model.body_pos(), self.id)
+    }
+
+    /// Orientation offset relative to the parent body
+    pub fn quat(&self) -> Quat {
+        quat(self.model.body_quat(), self.id)
+    }
+
+    /// Center of mass in the body frame
+    pub fn ipos(&self) -> Vec3 {
+        vec3(self.model.body_ipos(), self.id)
+    }
+
+    /// Orientation of the inertia frame relative to the body frame
+    pub fn iquat(&self) -> Quat {
+        quat(self.model.body_iquat(), self.id)
+    }
+
+    /// Mass
+    pub fn mass(&self) -> f64 {
+        self.model.body_mass()[self.id]
+    }
+
+    /// Mass of the subtree rooted at this body
+    pub fn subtree_mass(&self) -> f64 {
+        self.model.body_subtreemass()[self.id]
+    }
+
+    /// Diagonal inertia in the inertia frame
+    pub fn inertia(&self) -> Vec3 {
+        vec3(self.model.body_inertia(), self.id)
+    }
+
+    /// Gravity compensation (1 cancels gravity)
+    pub fn gravcomp(&self) -> f64 {
+        self.model.body_gravcomp()[self.id]
+    }
+
+    /// Ids of the joints of this body
+    pub fn joint_ids(&self) -> impl ExactSizeIterator<Item = JointId> + use<> {
+        let adr = self.model.body_jntadr()[self.id].max(0) as usize;
+        (adr..adr + self.model.body_jntnum()[self.id] as usize).map(JointId::from_index)
+    }
+
+    /// Ids of the geoms of this body
+    pub fn geom_ids(&self) -> impl ExactSizeIterator<Item = GeomId> + use<> {
+        let adr = self.model.body_geomadr()[self.id].max(0) as usize;
+        (adr..adr + self.model.body_geomnum()[self.id] as usize).map(GeomId::from_index)
+    }
+}
+
+impl ModelJoint<'_> {
+    /// Joint type
+    pub fn joint_type(&self) -> mjtJoint {
+        self.model.jnt_type()[self.id]
+    }
+
+    /// Id of the body the joint belongs to
+    pub fn body_id(&self) -> BodyId {
+        BodyId::from_index(self.model.jnt_bodyid()[self.id] as usize)
+    }
+
+    /// Start of the joint's coordinates in `qpos`
+    pub fn qpos_adr(&self) -> usize {
+        self.model.jnt_qposadr()[self.id] as usize
+    }
+
+    /// Start of the joint's degrees of freedom in `qvel`
+    pub fn dof_adr(&self) -> usize {
+        self.model.jnt_dofadr()[self.id] as usize
+    }
+
+    /// Number of position coordinates
+    pub fn nq(&self) -> usize {
+        crate::joint_nq(self.joint_type())
+    }
+
+    /// Number of degrees of freedom
+    pub fn nv(&self) -> usize {
+        crate::joint_nv(self.joint_type())
+    }
+
+    /// Anchor position in the body frame
+    pub fn pos(&self) -> Vec3 {
+        vec3(self.model.jnt_pos(), self.id)
+    }
+
+    /// Axis in the body frame (hinge and slide joints)
+    pub fn axis(&self) -> Vec3 {
+        vec3(self.model.jnt_axis(), self.id)
+    }
+
+    /// Joint limits, if the joint is limited
+    pub fn range(&self) -> Option<[f64; 2]> {
+        (self.model.jnt_limited()[self.id] != 0).then(|| pair(self.model.jnt_range(), self.id))
+    }
+
+    /// Stiffness of the joint spring
+    pub fn stiffness(&self) -> f64 {
+        self.model.jnt_stiffness()[self.id]
+    }
+
+    /// Margin of the limit constraint
+    pub fn margin(&self) -> f64 {
+        self.model.jnt_margin()[self.id]
+    }
+}
+
+impl ModelGeom<'_> {
+    /// Geometric type
+    pub fn geom_type(&self) -> mjtGeom {
+        self.model.geom_type()[self.id]
+    }
+
+    /// Id of the body the geom belongs to
+    pub fn body_id(&self) -> BodyId {
+        BodyId::from_index(self.model.geom_bodyid()[self.id] as usize)
+    }
+
+    /// Geom size parameters, meaning depends on the type
+    pub fn size(&self) -> Vec3 {
+        vec3(self.model.geom_size(), self.id)
+    }
+
+    /// Position in the body frame
+    pub fn pos(&self) -> Vec3 {
+        vec3(self.model.geom_pos(), self.id)
+    }
+
+    /// Orientation in the body frame
+    pub fn quat(&self) -> Quat {
+        quat(self.model.geom_quat(), self.id)
+    }
+
+    /// Sliding, torsional and rolling friction
+    pub fn friction(&self) -> Vec3 {
+        vec3(self.model.geom_friction(), self.id)
+    }
+
+    /// Contact dimensionality (1, 3, 4 or 6)
+    pub fn condim(&self) -> usize {
+        self.model.geom_condim()[self.id] as usize
+    }
+
+    /// Contact type bitmask
+    pub fn contype(&self) -> u32 {
+        self.model.geom_contype()[self.id] as u32
+    }
+
+    /// Contact affinity bitmask
+    pub fn conaffinity(&self) -> u32 {
+        self.model.geom_conaffinity()[self.id] as u32
+    }
+
+    /// Distance below which contacts are detected
+    pub fn margin(&self) -> f64 {
+        self.model.geom_margin()[self.id]
+    }
+
+    /// Part of the margin that does not generate contact forces
+    pub fn gap(&self) -> f64 {
+        self.model.geom_gap()[self.id]
+    }
+
+    /// Color and transparency
+    pub fn rgba(&self) -> [f32; 4] {
+        let rgba = &self.model.geom_rgba()[4 * self.id..4 * self.id + 4];
+        [rgba[0], rgba[1], rgba[2], rgba[3]]
+    }
+}
+
+impl ModelSite<'_> {
+    /// Geometric type used for rendering and sensor zones
+    pub fn site_type(&self) -> mjtGeom {
+        self.model.site_type()[self.id]
+    }
+
+    /// Id of the body the site belongs to
+    pub fn body_id(&self) -> BodyId {
+        BodyId::from_index(self.model.site_bodyid()[self.id] as usize)
+    }
+
+    /// Site size parameters, meaning depends on the type
+    pub fn size(&self) -> Vec3 {
+        vec3(self.model.site_size(), self.id)
+    }
+
+    /// Position in the body frame
+    pub fn pos(&self) -> Vec3 {
+        vec3(self.model.site_pos(), self.id)
+    }
+
+    /// Orientation in the body frame
+    pub fn quat(&self) -> Quat {
+        quat(self.model.site_quat(), self.id)
+    }
+}
+
+impl<'m> ModelActuator<'m> {
+    /// Transmission type
+    pub fn trn_type(&self) -> mjtTrn {
+        self.model.actuator_trntype()[self.id]
+    }
+
+    /// Activation dynamics type
+    pub fn dyn_type(&self) -> mjtDyn {
+        self.model.actuator_dyntype()[self.id]
+    }
+
+    /// Gain type
+    pub fn gain_type(&self) -> mjtGain {
+        self.model.actuator_gaintype()[self.id]
+    }
+
+    /// Bias type
+    pub fn bias_type(&self) -> mjtBias {
+        self.model.actuator_biastype()[self.id]
+    }
+
+    /// Ids of the transmission target(s), e.g. the joint; -1 when unused
+    pub fn trn_id(&self) -> [i32; 2] {
+        let trnid = self.model.actuator_trnid();
+        [trnid[2 * self.id], trnid[2 * self.id + 1]]
+    }
+
+    /// Control range, if controls are clamped
+    pub fn ctrl_range(&self) -> Option<[f64; 2]> {
+        (self.model.actuator_ctrllimited()[self.id] != 0)
+            .then(|| pair(self.model.actuator_ctrlrange(), self.id))
+    }
+
+    /// Force range, if forces are clamped
+    pub fn force_range(&self) -> Option<[f64; 2]> {
+        (self.model.actuator_forcelimited()[self.id] != 0)
+            .then(|| pair(self.model.actuator_forcerange(), self.id))
+    }
+
+    /// Transmission gear
+    pub fn gear(&self) -> &'m [f64] {
+        &self.model.actuator_gear()[6 * self.id..6 * self.id + 6]
+    }
+
+    /// Gain parameters
+    pub fn gainprm(&self) -> &'m [f64] {
+        let n = mujoco_sys::mjNGAIN as usize;
+        &self.model.actuator_gainprm()[n * self.id..n * (self.id + 1)]
+    }
+
+    /// Bias parameters
+    pub fn biasprm(&self) -> &'m [f64] {
+        let n = mujoco_sys::mjNBIAS as usize;
+        &self.model.actuator_biasprm()[n * self.id..n * (self.id + 1)]
+    }
+
+    /// Indices of the actuator's activations in `act`
+    pub fn act_ids(&self) -> Range<usize> {
+        let adr = self.model.actuator_actadr()[self.id].max(0) as usize;
+        adr..adr + self.model.actuator_actnum()[self.id] as usize
+    }
+}
+
+impl ModelSensor<'_> {
+    /// Sensor type
+    pub fn sensor_type(&self) -> mjtSensor {
+        self.model.sensor_type()[self.id]
+    }
+
+    /// Type of the sensed object
+    pub fn obj_type(&self) -> mjtObj {
+        self.model.sensor_objtype()[self.id]
+    }
+
+    /// Id of the sensed object, -1 when unused
+    pub fn obj_id(&self) -> i32 {
+        self.model.sensor_objid()[self.id]
+    }
+
+    /// Start of the sensor's values in `sensordata`
+    pub fn adr(&self) -> usize {
+        self.model.sensor_adr()[self.id] as usize
+    }
+
+    /// Number of values in `sensordata`
+    pub fn dim(&self) -> usize {
+        self.model.sensor_dim()[self.id] as usize
+    }
+
+    /// Cutoff applied to the values, 0 to disable
+    pub fn cutoff(&self) -> f64 {
+        self.model.sensor_cutoff()[self.id]
+    }
+
+    /// Noise standard deviation
+    pub fn noise(&self) -> f64 {
+        self.model.sensor_noise()[self.id]
+    }
+}
+
+impl ModelCamera<'_> {
+    /// Tracking mode
+    pub fn mode(&self) -> mjtCamLight {
+        self.model.cam_mode()[self.id]
+    }
+
+    /// Projection type
+    pub fn projection(&self) -> mjtProjection {
+        self.model.cam_projection()[self.id]
+    }
+
+    /// Id of the body the camera belongs to
+    pub fn body_id(&self) -> BodyId {
+        BodyId::from_index(self.model.cam_bodyid()[self.id] as usize)
+    }
+
+    /// Id of the target body in `TARGETBODY` modes
+    pub fn target_body_id(&self) -> Option<BodyId> {
+        optional(self.model.cam_targetbodyid()[self.id])
+    }
+
+    /// Position in the body frame
+    pub fn pos(&self) -> Vec3 {
+        vec3(self.model.cam_pos(), self.id)
+    }
+
+    /// Orientation in the body frame
+    pub fn quat(&self) -> Quat {
+        quat(self.model.cam_quat(), self.id)
+    }
+
+    /// Vertical field of view in degrees
+    pub fn fovy(&self) -> f64 {
+        self.model.cam_fovy()[self.id]
+    }
+
+    /// Inter-pupilary distance
+    pub fn ipd(&self) -> f64 {
+        self.model.cam_ipd()[self.id]
+    }
+
+    /// Resolution in pixels
+    pub fn resolution(&self) -> [i32; 2] {
+        let resolution = self.model.cam_resolution();
+        [resolution[2 * self.id], resolution[2 * self.id + 1]]
+    }
+}
+
+impl ModelTendon<'_> {
+    /// Indices of the tendon's path elements in the `wrap_*` arrays
+    pub fn wrap_ids(&self) -> Range<usize> {
+        let adr = self.model.tendon_adr()[self.id] as usize;
+        adr..adr + self.model.tendon_num()[self.id] as usize
+    }
+
+    /// Length limits, if the tendon is limited
+    pub fn range(&self) -> Option<[f64; 2]> {
+        (self.model.tendon_limited()[self.id] != 0)
+            .then(|| pair(self.model.tendon_range(), self.id))
+    }
+
+    /// Stiffness
+    pub fn stiffness(&self) -> f64 {
+        self.model.tendon_stiffness()[self.id]
+    }
+
+    /// Damping
+    pub fn damping(&self) -> f64 {
+        self.model.tendon_damping()[self.id]
+    }
+
+    /// Length in the reference configuration
+    pub fn length0(&self) -> f64 {
+        self.model.tendon_length0()[self.id]
+    }
+
+    /// Width used for rendering
+    pub fn width(&self) -> f64 {
+        self.model.tendon_width()[self.id]
+    }
+}
+
+impl Model {
+    /// Views of all keyframes, in model order
+    pub fn keyframes(&self) -> impl ExactSizeIterator<Item = ModelKeyframe<'_>> {
+        (0..self.nkey()).map(|id| ModelKeyframe { model: self, id })
+    }
+}
+
+impl<'m> ModelKeyframe<'m> {
+    fn row(&self, values: &'m [f64], size: usize) -> &'m [f64] {
+        &values[size * self.id..size * (self.id + 1)]
+    }
+
+    /// Simulation time
+    pub fn time(&self) -> f64 {
+        self.model.key_time()[self.id]
+    }
+
+    /// Position coordinates, `nq` values
+    pub fn qpos(&self) -> &'m [f64] {
+        self.row(self.model.key_qpos(), self.model.nq())
+    }
+
+    /// Velocities, `nv` values
+    pub fn qvel(&self) -> &'m [f64] {
+        self.row(self.model.key_qvel(), self.model.nv())
+    }
+
+    /// Actuator activations, `na` values
+    pub fn act(&self) -> &'m [f64] {
+        self.row(self.model.key_act(), self.model.na())
+    }
+
+    /// Controls, `nu` values
+    pub fn ctrl(&self) -> &'m [f64] {
+        self.row(self.model.key_ctrl(), self.model.nu())
+    }
+
+    /// Positions of the mocap bodies
+    pub fn mpos(&self) -> Vec<Vec3> {
+        let nmocap = self.model.nmocap();
+        (0..nmocap)
+            .map(|i| vec3(self.model.key_mpos(), nmocap * self.id + i))
+            .collect()
+    }
+
+    /// Orientations of the mocap bodies
+    pub fn mquat(&self) -> Vec<Quat> {
+        let nmocap = self.model.nmocap();
+        (0..nmocap)
+            .map(|i| quat(self.model.key_mquat(), nmocap * self.id + i))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Element, Error};
+    use std::str::FromStr;
+
+    #[test]
+    #[allow(clippy::approx_constant)] // Limits as written in rrr.xml
+    fn test_model_views() {
+        let model = Model::from_file(crate::tests::test_xml_path()).unwrap();
+
+        let link2 = model.body("link2").unwrap();
+        assert_eq!(link2.name(), "link2");
+        assert_eq!(link2.parent_id(), model.body("link1").unwrap().id());
+        assert_eq!(link2.pos(), Vec3::new(0.0, 0.0, 0.5));
+        assert_eq!(link2.quat(), Quat::identity());
+        assert_eq!(link2.joint_ids().len(), 1);
+        assert!(link2.mass() > 0.0);
+
+        let joint = model.joint("joint2").unwrap();
+        assert_eq!(joint.joint_type(), mjtJoint::HINGE);
+        assert_eq!(joint.body_id(), link2.id());
+        assert_eq!((joint.nq(), joint.nv()), (1, 1));
+        assert_eq!(joint.axis(), Vec3::y());
+        assert_eq!(joint.range(), Some([-3.14, 3.14]));
+
+        let geom = link2.geom_ids().next().unwrap();
+        assert_eq!(model.geom(geom).unwrap().geom_type(), mjtGeom::BOX);
+
+        let actuator = model.actuator("joint3").unwrap();
+        assert_eq!(actuator.trn_type(), mjtTrn::JOINT);
+        assert_eq!(actuator.dyn_type(), mjtDyn::NONE);
+        assert_eq!(actuator.bias_type(), mjtBias::AFFINE);
+        assert_eq!(
+            actuator.trn_id()[0] as usize,
+            model.joint("joint3").unwrap().id().index()
+        );
+        assert_eq!(actuator.ctrl_range(), Some([-6.28, 6.28]));
+        assert_eq!(actuator.gainprm()[0], 260.0);
+
+        assert!(matches!(model.body("missing"), Err(Error::NotFound(_))));
+        assert!(matches!(model.body("a\0b"), Err(Error::InvalidName(_))));
+    }
+
+    #[test]
+    fn test_sensor_site_camera_views() {
+        let model = crate::Spec::from_str(
+            r#"<mujoco>
+              <worldbody>
+                <camera name="overview" pos="0 -1 1" fovy="60" />
+                <body name="box">
+                  <freejoint />
+                  <geom name="box" type="box" size="0.1 0.2 0.3" friction="0.8 0.01 0.001" />
+                  <site name="imu" type="sphere" size="0.01" pos="0 0 0.3" />
+                </body>
+              </worldbody>
+              <sensor>
+                <accelerometer name="acc" site="imu" />
+                <gyro name="gyro" site="imu" noise="0.1" />
+              </sensor>
+            </mujoco>"#,
+        )
+        .unwrap()
+        .compile()
+        .unwrap();
+
+        let geom = model.geom("box").unwrap();
+        assert_eq!(geom.geom_type(), mjtGeom::BOX);
+        assert_eq!(geom.size(), Vec3::new(0.1, 0.2, 0.3));
+        assert_eq!(geom.friction(), Vec3::new(0.8, 0.01, 0.001));
+
+        let site = model.site("imu").unwrap();
+        assert_eq!(site.site_type(), mjtGeom::SPHERE);
+        assert_eq!(site.body_id(), model.body("box").unwrap().id());
+        assert_eq!(site.pos(), Vec3::new(0.0, 0.0, 0.3));
+
+        let gyro = model.sensor("gyro").unwrap();
+        assert_eq!(gyro.sensor_type(), mjtSensor::GYRO);
+        assert_eq!(gyro.obj_type(), mjtObj::SITE);
+        assert_eq!(gyro.obj_id() as usize, site.id().index());
+        assert_eq!((gyro.adr(), gyro.dim()), (3, 3));
+        assert_eq!(gyro.noise(), 0.1);
+
+        let camera = model.camera("overview").unwrap();
+        assert_eq!(camera.mode(), mjtCamLight::FIXED);
+        assert_eq!(camera.body_id(), BodyId::WORLD);
+        assert_eq!(camera.fovy(), 60.0);
+        assert!(model.tendon("none").is_err());
+    }
+
+    #[test]
+    fn test_keyframes() {
+        let mut spec = crate::Spec::from_str(
+            r#"<mujoco>
+              <worldbody>
+                <body name="target" mocap="true" pos="1 0 0" />
+                <body name="arm">
+                  <joint name="hinge" />
+                  <geom type="capsule" size="0.05" fromto="0 0 0 0 0 -0.5" />
+                </body>
+              </worldbody>
+              <actuator><motor joint="hinge" /></actuator>
+              <keyframe><key name="zero" /></keyframe>
+            </mujoco>"#,
+        )
+        .unwrap();
+        let (mpos, mquat) = (
+            Vec3::new(0.0, 1.0, 2.0),
+            Quat::from_euler_angles(0.0, 0.0, 0.5),
+        );
+        spec.add_keyframe()
+            .unwrap()
+            .set_name("home")
+            .unwrap()
+            .set_time(1.5)
+            .set_qpos(&[0.3])
+            .unwrap()
+            .set_ctrl(&[-1.0])
+            .unwrap()
+            .set_mpos(&[mpos])
+            .unwrap()
+            .set_mquat(&[mquat])
+            .unwrap();
+        assert_eq!(spec.keyframe("home").unwrap().qpos(), vec![0.3]);
+        assert_eq!(spec.keyframe("home").unwrap().mpos(), vec![mpos]);
+        let mut model = spec.compile().unwrap();
+
+        let names: Vec<_> = model.keyframes().map(|key| key.name()).collect();
+        assert_eq!(names, ["zero", "home"]);
+        let home = model.keyframe("home").unwrap();
+        assert_eq!(home.time(), 1.5);
+        assert_eq!(
+            (home.qpos(), home.qvel(), home.ctrl()),
+            (&[0.3][..], &[0.0][..], &[-1.0][..])
+        );
+        assert!(home.act().is_empty());
+        assert_eq!(home.mpos(), vec![mpos]);
+        assert!(home.mquat()[0].angle_to(&mquat) < 1e-12);
+
+        let mut data = crate::Data::new(&model);
+        data.reset_to_keyframe("home").unwrap();
+        assert_eq!(
+            (data.time(), data.qpos()[0], data.ctrl()[0]),
+            (1.5, 0.3, -1.0)
+        );
+        assert!(matches!(
+            data.reset_to_keyframe("away"),
+            Err(Error::NotFound(_))
+        ));
+
+        // Capture a new state into the first key
+        data.qpos_mut()[0] = -0.7;
+        let data = data.detach();
+        let zero = model.keyframe("zero").unwrap().id();
+        model.set_keyframe(zero, &data).unwrap();
+        assert_eq!(model.keyframe(zero).unwrap().qpos(), &[-0.7]);
+        assert_eq!(model.keyframe(zero).unwrap().time(), 1.5);
+
+        // State of another model, even an identical copy, is rejected
+        let other = model.try_clone().unwrap();
+        let other_data = crate::Data::new(&other).detach();
+        assert!(matches!(
+            model.set_keyframe(zero, &other_data),
+            Err(crate::Error::ModelMismatch(_))
+        ));
+    }
+}
@@ -0,0 +1,489 @@
+//! Typed access to the physics options (`mjOption`) of a model
+//!
+//! [`Model::options`] and [`Model::options_mut`] return the options stored in the
+//! model, so changes apply to the next step of every [`crate::Data`] using it.
+//! Setters of numeric options reject values MuJoCo cannot simulate with and leave
+//! the option unchanged. [`Options`] is `Copy`, so the options can be saved before a
+//! parameter sweep and restored afterwards with `*model.options_mut() = saved`.
+//! The disable and enable flags are read and written with [`Model::disable_flags`]
+//! and [`Model::enable_flags`].
+
+use crate::error::{Error, Result};
+use crate::{Model, Vec3};
+use mujoco_sys::{mjOption, mjtCone, mjtIntegrator, mjtJacobian, mjtSolver};
+
+macro_rules! option_enum {
+    ($(#[$doc:meta])* $name:ident: $sys:ident { $($(#[$variant_doc:meta])* $variant:ident = $sys_variant:ident,)* }) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub enum $name {
+            $($(#[$variant_doc])* $variant,)*
+        }
+
+        impl TryFrom<i32> for $name {
+            type Error = Error;
+
+            fn try_from(value: i32) -> Result<Self> {
+                match value {
+                    $(v if v == $sys::$sys_variant as i32 => Ok($name::$variant),)*
+                    _ => Err(Error::InvalidOption(format!(
+                        concat!("unknown ", stringify!($sys), " code {}"),
+                        value
+                    ))),
+                }
+            }
+        }
+
+        impl From<$name> for $sys {
+            fn from(value: $name) -> Self {
+                match value {
+                    $($name::$variant => $sys::$sys_variant,)*
+                }
+            }
+        }
+
+        impl From<$sys> for $name {
+            fn from(value: $sys) -> Self {
+                match value {
+                    $($sys::$sys_variant => $name::$variant,)*
+                }
+            }
+        }
+    };
+}
+
+option_enum!(
+    /// Numerical integrator (mjtIntegrator)
+    Integrator: mjtIntegrator {
+        /// Semi-implicit Euler
+        Euler = EULER,
+        /// Fourth-order Runge-Kutta
+        Rk4 = RK4,
+        /// Implicit in velocity
+        Implicit = IMPLICIT,
+        /// Implicit in velocity, without the Coriolis and centrifugal terms
+        ImplicitFast = IMPLICITFAST,
+    }
+);
+option_enum!(
+    /// Constraint solver (mjtSolver)
+    Solver: mjtSolver {
+        /// Projected Gauss-Seidel
+        Pgs = PGS,
+        /// Conjugate gradient
+        Cg = CG,
+        /// Newton
+        Newton = NEWTON,
+    }
+);
+option_enum!(
+    /// Type of friction cone (mjtCone)
+    Cone: mjtCone {
+        /// Pyramidal
+        Pyramidal = PYRAMIDAL,
+        /// Elliptic
+        Elliptic = ELLIPTIC,
+    }
+);
+option_enum!(
+    /// Layout of the constraint Jacobian (mjtJacobian)
+    Jacobian: mjtJacobian {
+        /// Dense
+        Dense = DENSE,
+        /// Sparse
+        Sparse = SPARSE,
+        /// Dense if `nv` < 60, sparse otherwise
+        Auto = AUTO,
+    }
+);
+
+/// Physics options of a model, see [`Model::options`]
+#[derive(Debug, Clone, Copy)]
+#[repr(transparent)]
+pub struct Options(mjOption);
+
+impl Model {
+    /// Physics options
+    pub fn options(&self) -> &Options {
+        // SAFETY: Options is a transparent wrapper around mjOption
+        unsafe { &*(&self.raw().opt as *const mjOption as *const Options) }
+    }
+
+    /// Mutable physics options
+    pub fn options_mut(&mut self) -> &mut Options {
+        // SAFETY: Options is a transparent wrapper around mjOption
+        unsafe { &mut *(&mut self.raw_mut().opt as *mut mjOption as *mut Options) }
+    }
+}
+
+/// Check that `value` is finite and above `min` (or equal to it, if `inclusive`)
+fn check_min(name: &str, value: f64, min: f64, inclusive: bool) -> Result<f64> {
+    let valid = value.is_finite() && (value > min || (inclusive && value == min));
+    if !valid {
+        let bound = if inclusive {
+            "at least"
+        } else {
+            "greater than"
+        };
+        return Err(Error::InvalidOption(format!(
+            "{name} must be {bound} {min}, got {value}"
+        )));
+    }
+    Ok(value)
+}
+
+/// Check that every component of a vector option is finite
+fn check_finite(name: &str, value: &Vec3) -> Result<[f64; 3]> {
+    if !value.iter().all(|component| component.is_finite()) {
+        return Err(Error::InvalidOption(format!(
+            "{name} must be finite, got {:?}",
+            value.as_slice()
+        )));
+    }
+    Ok((*value).into())
+}
+
+/// Convert an iteration count to MuJoCo's `int`, checking its lower bound
+fn check_iterations(name: &str, value: usize, min: usize) -> Result<i32> {
+    if value < min {
+        return Err(Error::InvalidOption(format!(
+            "{name} must be at least {min}, got {value}"
+        )));
+    }
+    i32::try_from(value)
+        .map_err(|_| Error::InvalidOption(format!("{name} is too large, got {value}")))
+}
+
+impl Options {
+    /// Underlying MuJoCo options
+    pub fn raw(&self) -> &mjOption {
+        &self.0
+    }
+
+    /// Timestep
+    pub fn timestep(&self) -> f64 {
+        self.0.timestep
+    }
+
+    /// Set the timestep, which must be positive
+    pub fn set_timestep(&mut self, timestep: f64) -> Result<&mut Self> {
+        self.0.timestep = check_min("timestep", timestep, 0.0, false)?;
+        Ok(self)
+    }
+
+    /// Ratio of frictional-to-normal constraint impedance
+    pub fn impratio(&self) -> f64 {
+        self.0.impratio
+    }
+
+    /// Set the frictional-to-normal impedance ratio, which must be positive
+    pub fn set_impratio(&mut self, impratio: f64) -> Result<&mut Self> {
+        self.0.impratio = check_min("impratio", impratio, 0.0, false)?;
+        Ok(self)
+    }
+
+    /// Main solver tolerance
+    pub fn tolerance(&self) -> f64 {
+        self.0.tolerance
+    }
+
+    /// Set the main solver tolerance, 0 disables early termination
+    pub fn set_tolerance(&mut self, tolerance: f64) -> Result<&mut Self> {
+        self.0.tolerance = check_min("tolerance", tolerance, 0.0, true)?;
+        Ok(self)
+    }
+
+    /// Linesearch tolerance
+    pub fn ls_tolerance(&self) -> f64 {
+        self.0.ls_tolerance
+    }
+
+    /// Set the linesearch tolerance
+    pub fn set_ls_tolerance(&mut self, tolerance: f64) -> Result<&mut Self> {
+        self.0.ls_tolerance = check_min("ls_tolerance", tolerance, 0.0, true)?;
+        Ok(self)
+    }
+
+    /// Noslip solver tolerance
+    pub fn noslip_tolerance(&self) -> f64 {
+        self.0.noslip_tolerance
+    }
+
+    /// Set the noslip solver tolerance
+    pub fn set_noslip_tolerance(&mut self, tolerance: f64) -> Result<&mut Self> {
+        self.0.noslip_tolerance = check_min("noslip_tolerance", tolerance, 0.0, true)?;
+        Ok(self)
+    }
+
+    /// Convex collision solver tolerance
+    pub fn ccd_tolerance(&self) -> f64 {
+        self.0.ccd_tolerance
+    }
+
+    /// Set the convex collision solver tolerance
+    pub fn set_ccd_tolerance(&mut self, tolerance: f64) -> Result<&mut Self> {
+        self.0.ccd_tolerance = check_min("ccd_tolerance", tolerance, 0.0, true)?;
+        Ok(self)
+    }
+
+    /// Gravitational acceleration
+    pub fn gravity(&self) -> Vec3 {
+        Vec3::from(self.0.gravity)
+    }
+
+    /// Set the gravitational acceleration, which must be finite
+    pub fn set_gravity(&mut self, gravity: &Vec3) -> Result<&mut Self> {
+        self.0.gravity = check_finite("gravity", gravity)?;
+        Ok(self)
+    }
+
+    /// Wind, for lift, drag and viscosity
+    pub fn wind(&self) -> Vec3 {
+        Vec3::from(self.0.wind)
+    }
+
+    /// Set the wind velocity, which must be finite
+    pub fn set_wind(&mut self, wind: &Vec3) -> Result<&mut Self> {
+        self.0.wind = check_finite("wind", wind)?;
+        Ok(self)
+    }
+
+    /// Global magnetic flux
+    pub fn magnetic(&self) -> Vec3 {
+        Vec3::from(self.0.magnetic)
+    }
+
+    /// Set the global magnetic flux, which must be finite
+    pub fn set_magnetic(&mut self, magnetic: &Vec3) -> Result<&mut Self> {
+        self.0.magnetic = check_finite("magnetic", magnetic)?;
+        Ok(self)
+    }
+
+    /// Density of the medium
+    pub fn density(&self) -> f64 {
+        self.0.density
+    }
+
+    /// Set the density of the medium, 0 disables lift and drag
+    pub fn set_density(&mut self, density: f64) -> Result<&mut Self> {
+        self.0.density = check_min("density", density, 0.0, true)?;
+        Ok(self)
+    }
+
+    /// Viscosity of the medium
+    pub fn viscosity(&self) -> f64 {
+        self.0.viscosity
+    }
+
+    /// Set the viscosity of the medium, 0 disables viscous forces
+    pub fn set_viscosity(&mut self, viscosity: f64) -> Result<&mut Self> {
+        self.0.viscosity = check_min("viscosity", viscosity, 0.0, true)?;
+        Ok(self)
+    }
+
+    /// Numerical integrator
+    ///
+    /// Returns [`Error::InvalidOption`] if the raw option holds an unknown code.
+    pub fn integrator(&self) -> Result<Integrator> {
+        Integrator::try_from(self.0.integrator)
+    }
+
+    /// Set the numerical integrator
+    pub fn set_integrator(&mut self, integrator: Integrator) -> &mut Self {
+        self.0.integrator = mjtIntegrator::from(integrator) as i32;
+        self
+    }
+
+    /// Constraint solver
+    ///
+    /// Returns [`Error::InvalidOption`] if the raw option holds an unknown code.
+    pub fn solver(&self) -> Result<Solver> {
+        Solver::try_from(self.0.solver)
+    }
+
+    /// Set the constraint solver
+    pub fn set_solver(&mut self, solver: Solver) -> &mut Self {
+        self.0.solver = mjtSolver::from(solver) as i32;
+        self
+    }
+
+    /// Type of friction cone
+    ///
+    /// Returns [`Error::InvalidOption`] if the raw option holds an unknown code.
+    pub fn cone(&self) -> Result<Cone> {
+        Cone::try_from(self.0.cone)
+    }
+
+    /// Set the type of friction cone
+    pub fn set_cone(&mut self, cone: Cone) -> &mut Self {
+        self.0.cone = mjtCone::from(cone) as i32;
+        self
+    }
+
+    /// Layout of the constraint Jacobian
+    ///
+    /// Returns [`Error::InvalidOption`] if the raw option holds an unknown code.
+    pub fn jacobian(&self) -> Result<Jacobian> {
+        Jacobian::try_from(self.0.jacobian)
+    }
+
+    /// Set the layout of the constraint Jacobian
+    pub fn set_jacobian(&mut self, jacobian: Jacobian) -> &mut Self {
+        self.0.jacobian = mjtJacobian::from(jacobian) as i32;
+        self
+    }
+
+    /// Maximum number of main solver iterations
+    pub fn iterations(&self) -> usize {
+        self.0.iterations as usize
+    }
+
+    /// Set the maximum number of main solver iterations, at least 1
+    pub fn set_iterations(&mut self, iterations: usize) -> Result<&mut Self> {
+        self.0.iterations = check_iterations("iterations", iterations, 1)?;
+        Ok(self)
+    }
+
+    /// Maximum number of linesearch iterations
+    pub fn ls_iterations(&self) -> usize {
+        self.0.ls_iterations as usize
+    }
+
+    /// Set the maximum number of linesearch iterations, at least 1
+    pub fn set_ls_iterations(&mut self, iterations: usize) -> Result<&mut Self> {
+        self.0.ls_iterations = check_iterations("ls_iterations", iterations, 1)?;
+        Ok(self)
+    }
+
+    /// Maximum number of noslip solver iterations
+    pub fn noslip_iterations(&self) -> usize {
+        self.0.noslip_iterations as usize
+    }
+
+    /// Set the maximum number of noslip solver iterations, 0 disables the noslip solver
+    pub fn set_noslip_iterations(&mut self, iterations: usize) -> Result<&mut Self> {
+        self.0.noslip_iterations = check_iterations("noslip_iterations", iterations, 0)?;
+        Ok(self)
+    }
+
+    /// Maximum number of convex collision solver iterations
+    pub fn ccd_iterations(&self) -> usize {
+        self.0.ccd_iterations as usize
+    }
+
+    /// Set the maximum number of convex collision solver iterations, at least 1
+    pub fn set_ccd_iterations(&mut self, iterations: usize) -> Result<&mut Self> {
+        self.0.ccd_iterations = check_iterations("ccd_iterations", iterations, 1)?;
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_options_roundtrip() {
+        let mut model = Model::from_file(crate::tests::test_xml_path()).unwrap();
+        assert_eq!(model.options().timestep(), model.opt().timestep);
+        assert_eq!(model.options().gravity(), Vec3::new(0.0, 0.0, -9.81));
+        let saved = *model.options();
+
+        model
+            .options_mut()
+            .set_integrator(Integrator::ImplicitFast)
+            .set_solver(Solver::Cg)
+            .set_cone(Cone::Elliptic)
+            .set_jacobian(Jacobian::Sparse)
+            .set_wind(&Vec3::new(1.0, 0.0, 0.0))
+            .unwrap()
+            .set_timestep(0.001)
+            .unwrap()
+            .set_iterations(5)
+            .unwrap();
+        let opt = model.opt();
+        assert_eq!(opt.integrator, mjtIntegrator::IMPLICITFAST as i32);
+        assert_eq!(opt.solver, mjtSolver::CG as i32);
+        assert_eq!(opt.cone, mjtCone::ELLIPTIC as i32);
+        assert_eq!(opt.jacobian, mjtJacobian::SPARSE as i32);
+        assert_eq!(opt.wind, [1.0, 0.0, 0.0]);
+        assert_eq!((opt.timestep, opt.iterations), (0.001, 5));
+        assert_eq!(
+            model.options().integrator().unwrap(),
+            Integrator::ImplicitFast
+        );
+        assert_eq!(Integrator::from(mjtIntegrator::RK4), Integrator::Rk4);
+
+        *model.options_mut() = saved;
+        assert_eq!(model.options().solver().unwrap(), saved.solver().unwrap());
+
+        // Codes written through the raw options are reported instead of panicking
+        model.raw_mut().opt.integrator = 42;
+        assert!(matches!(
+            model.options().integrator(),
+            Err(Error::InvalidOption(message)) if message.contains("42")
+        ));
+        assert_eq!(model.opt().timestep, saved.timestep());
+    }
+
+    #[test]
+    fn test_invalid_options_are_rejected() {
+        let mut model = Model::from_file(crate::tests::test_xml_path()).unwrap();
+        let timestep = model.options().timestep();
+        let options = model.options_mut();
+        for invalid in [0.0, -0.01, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                options.set_timestep(invalid),
+                Err(Error::InvalidOption(_))
+            ));
+        }
+        assert!(options.set_iterations(0).is_err());
+        assert!(options.set_iterations(usize::MAX).is_err());
+        assert!(options.set_density(-1.0).is_err());
+        assert!(options.set_noslip_iterations(0).is_ok());
+        let gravity = options.gravity();
+        assert!(matches!(
+            options.set_gravity(&Vec3::new(0.0, f64::NAN, 0.0)),
+            Err(Error::InvalidOption(_))
+        ));
+        assert!(
+            options
+                .set_wind(&Vec3::new(f64::INFINITY, 0.0, 0.0))
+                .is_err()
+        );
+        assert!(
+            options
+                .set_magnetic(&Vec3::new(0.0, 0.0, f64::NEG_INFINITY))
+                .is_err()
+        );
+        assert_eq!(model.options().gravity(), gravity);
+        assert_eq!(model.options().timestep(), timestep);
+    }
+
+    #[test]
+    fn test_gravity_change_applies_to_simulation() {
+        let mut model = crate::Spec::from_str(
+            r#"<mujoco>
+              <worldbody>
+                <body name="ball">
+                  <freejoint />
+                  <geom type="sphere" size="0.1" />
+                </body>
+              </worldbody>
+            </mujoco>"#,
+        )
+        .unwrap()
+        .compile()
+        .unwrap();
+        model
+            .options_mut()
+            .set_gravity(&Vec3::new(1.0, 0.0, 0.0))
+            .unwrap();
+        let mut data = crate::Data::new(&model);
+        crate::forward(&mut data).unwrap();
+        assert!((data.qacc()[0] - 1.0).abs() < 1e-9);
+        assert!(data.qacc()[2].abs() < 1e-9);
+    }
+}
@@ -0,0 +1,269 @@
+//! Parallel batched rollouts, mirroring the `rollout` module of MuJoCo's Python bindings
+//!
+//! A rollout starts from an initial [`State`], applies a sequence of controls and
+//! records the state and sensor data after every step. Batches are split across the
+//! given `Data` instances, each of which is stepped on its own rayon task.
+//!
+//! All buffers are flat and laid out as `[batch][step][value]`.
+
+use crate::error::{Error, Result};
+use crate::{Data, State, StateFlags};
+use rayon::prelude::*;
+
+/// Components written to [`RolloutOutput::state`], matching MuJoCo's `rollout`
+pub const ROLLOUT_STATE: StateFlags = StateFlags::FULLPHYSICS;
+
+/// Preallocated buffers the rollout writes into
+#[derive(Debug, Default)]
+pub struct RolloutOutput<'a> {
+    /// `nbatch * nstep * state_size(model, ROLLOUT_STATE)` values
+    pub state: Option<&'a mut [f64]>,
+    /// `nbatch * nstep * nsensordata` values
+    pub sensordata: Option<&'a mut [f64]>,
+}
+
+/// Work assigned to one `Data` instance
+struct Chunk<'a, 'd> {
+    data: &'a mut Data<'d>,
+    baseline: &'a State,
+    initial_state: &'a [State],
+    control: Option<&'a [f64]>,
+    state: Option<&'a mut [f64]>,
+    sensordata: Option<&'a mut [f64]>,
+}
+
+/// Split an optional buffer after `mid` values
+fn split_opt<'a>(buffer: &mut Option<&'a mut [f64]>, mid: usize) -> Option<&'a mut [f64]> {
+    let (head, tail) = buffer.take()?.split_at_mut(mid);
+    *buffer = Some(tail);
+    Some(head)
+}
+
+fn check_len(name: &str, len: usize, expected: usize) -> Result<()> {
+    if len != expected {
+        return Err(Error::InvalidBuffer(format!(
+            "{name} has {len} values, expected {expected}"
+        )));
+    }
+    Ok(())
+}
+
+/// Roll out `initial_state.len()` trajectories of `nstep` steps each
+///
+/// Every initial state must contain at least the [`ROLLOUT_STATE`] components.
+/// Components it does not cover (e.g. controls or applied forces) are taken from the
+/// first `Data` as it is when the rollout starts, so each trajectory starts from the
+/// same baseline no matter how the batch is split. `control`, if given, holds
+/// `nbatch * nstep * nu` values applied before each step; otherwise the baseline
+/// controls are kept. The batch is divided evenly over `data`, so pass one `Data`
+/// per desired thread.
+pub fn rollout(
+    data: &mut [Data<'_>],
+    initial_state: &[State],
+    control: Option<&[f64]>,
+    nstep: usize,
+    mut output: RolloutOutput<'_>,
+) -> Result<()> {
+    let Some(first) = data.first() else {
+        return Err(Error::InvalidBuffer(
+            "rollout needs at least one Data".into(),
+        ));
+    };
+    let model = first.model();
+    if data
+        .iter()
+        .any(|d| !std::ptr::eq(d.model().as_ptr(), model.as_ptr()))
+    {
+        return Err(Error::ModelMismatch(
+            "all Data instances of a rollout must share one model".into(),
+        ));
+    }
+
+    if let Some(initial) = initial_state
+        .iter()
+        .find(|s| !s.flags().contains(ROLLOUT_STATE))
+    {
+        return Err(Error::InvalidBuffer(format!(
+            "initial state with {:?} lacks components of {:?}",
+            initial.flags(),
+            ROLLOUT_STATE
+        )));
+    }
+    let baseline = State::capture(first, StateFlags::INTEGRATION);
+
+    let nbatch = initial_state.len();
+    let nu = model.nu();
+    let nstate = crate::state_size(model, ROLLOUT_STATE);
+    let nsensordata = model.nsensordata();
+    if let Some(control) = control {
+        check_len("control", control.len(), nbatch * nstep * nu)?;
+    }
+    if let Some(state) = &output.state {
+        check_len("state output", state.len(), nbatch * nstep * nstate)?;
+    }
+    if let Some(sensordata) = &output.sensordata {
+        check_len(
+            "sensordata output",
+            sensordata.len(),
+            nbatch * nstep * nsensordata,
+        )?;
+    }
+
+    let chunk_size = nbatch.div_ceil(data.len()).max(1);
+    let mut chunks = Vec::with_capacity(data.len());
+    let mut initial_state = initial_state;
+    let mut control = control;
+    for data in data.iter_mut() {
+        let n = chunk_size.min(initial_state.len());
+        if n == 0 {
+            break;
+        }
+        let (initial, rest) = initial_state.split_at(n);
+        initial_state = rest;
+        chunks.push(Chunk {
+            data,
+            baseline: &baseline,
+            initial_state: initial,
+            control: control.map(|c| {
+                let (head, tail) = c.split_at(n * nstep * nu);
+                control = Some(tail);
+                head
+            }),
+            state: split_opt(&mut output.state, n * nstep * nstate),
+            sensordata: split_opt(&mut output.sensordata, n * nstep * nsensordata),
+        });
+    }
+
+    chunks
+        .into_par_iter()
+        .try_for_each(|chunk| run_chunk(chunk, nstep))
+}
+
+fn run_chunk(chunk: Chunk<'_, '_>, nstep: usize) -> Result<()> {
+    let Chunk {
+        data,
+        baseline,
+        initial_state,
+        control,
+        mut state,
+        mut sensordata,
+    } = chunk;
+    let nu = data.model().nu();
+    let nsensordata = data.model().nsensordata();
+    let mut current = State::new(data.model(), ROLLOUT_STATE);
+    let nstate = current.as_slice().len();
+
+    for (batch, initial) in initial_state.iter().enumerate() {
+        baseline.restore(data)?;
+        initial.restore(data)?;
+        for step in 0..nstep {
+            let index = batch * nstep + step;
+            if let Some(control) = control {
+                data.ctrl_mut()
+                    .copy_from_slice(&control[index * nu..(index + 1) * nu]);
+            }
+            crate::step(data)?;
+            if let Some(state) = state.as_deref_mut() {
+                current.capture_from(data)?;
+                state[index * nstate..(index + 1) * nstate].copy_from_slice(current.as_slice());
+            }
+            if let Some(sensordata) = sensordata.as_deref_mut() {
+                sensordata[index * nsensordata..(index + 1) * nsensordata]
+                    .copy_from_slice(data.sensordata());
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rollout_matches_sequential() {
+        let model = crate::Model::from_file(crate::tests::test_xml_path()).unwrap();
+        let (nbatch, nstep, nu) = (5, 4, model.nu());
+        let nstate = crate::state_size(&model, ROLLOUT_STATE);
+
+        let mut data = Data::new(&model);
+        let initial_state: Vec<State> = (0..nbatch)
+            .map(|i| {
+                data.qpos_mut()[0] = 0.1 * i as f64;
+                State::capture(&data, StateFlags::INTEGRATION)
+            })
+            .collect();
+        let control: Vec<f64> = (0..nbatch * nstep * nu).map(|i| i as f64 * 0.01).collect();
+
+        let mut pool = vec![Data::new(&model), Data::new(&model)];
+        let mut state = vec![0.0; nbatch * nstep * nstate];
+        rollout(
+            &mut pool,
+            &initial_state,
+            Some(&control),
+            nstep,
+            RolloutOutput {
+                state: Some(&mut state),
+                sensordata: None,
+            },
+        )
+        .unwrap();
+
+        let mut expected = Vec::new();
+        for (batch, initial) in initial_state.iter().enumerate() {
+            initial.restore(&mut data).unwrap();
+            for step in 0..nstep {
+                let index = batch * nstep + step;
+                data.ctrl_mut()
+                    .copy_from_slice(&control[index * nu..(index + 1) * nu]);
+                crate::step(&mut data).unwrap();
+                expected.extend_from_slice(State::capture(&data, ROLLOUT_STATE).as_slice());
+            }
+        }
+        assert_eq!(state, expected);
+
+        // Without controls, the result does not depend on the state of the other Data
+        let mut single = vec![0.0; nbatch * nstep * nstate];
+        let mut split = vec![0.0; nbatch * nstep * nstate];
+        for (pool, out) in [(1, &mut single), (3, &mut split)] {
+            let mut pool: Vec<Data> = (0..pool).map(|_| Data::new(&model)).collect();
+            for data in &mut pool[1..] {
+                data.ctrl_mut().fill(5.0);
+                data.qfrc_applied_mut().fill(1.0);
+            }
+            rollout(
+                &mut pool,
+                &initial_state,
+                None,
+                nstep,
+                RolloutOutput {
+                    state: Some(out),
+                    sensordata: None,
+                },
+            )
+            .unwrap();
+        }
+        assert_eq!(single, split);
+
+        let qpos_only = [State::capture(&data, StateFlags::QPOS)];
+        assert!(matches!(
+            rollout(&mut pool, &qpos_only, None, nstep, RolloutOutput::default()),
+            Err(Error::InvalidBuffer(_))
+        ));
+
+        let mut short = vec![0.0; 1];
+        assert!(matches!(
+            rollout(
+                &mut pool,
+                &initial_state,
+                None,
+                nstep,
+                RolloutOutput {
+                    state: Some(&mut short),
+                    sensordata: None,
+                },
+            ),
+            Err(Error::InvalidBuffer(_))
+        ));
+    }
+}
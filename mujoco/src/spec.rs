@@ -1,33 +1,35 @@
-use std::ffi::CStr;
-use std::fmt;
+use crate::error::{Error, ErrorInfo, Result, error_buf_to_string, to_cstring};
 use std::str::FromStr;
 
-/// Error returned when parsing or loading a MuJoCo spec fails.
-#[derive(Debug, Clone)]
-pub struct ParseError(String);
-
-impl fmt::Display for ParseError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(&self.0)
-    }
-}
-
-impl std::error::Error for ParseError {}
-
 // Safe Rust wrapper around MuJoCo's mjSpec
 pub struct Spec {
     pub(crate) ptr: *mut mujoco_sys::mjSpec,
 }
 
 impl Spec {
-    pub fn from_file(filename: impl AsRef<std::path::Path>) -> Result<Self, ParseError> {
-        let c_filename = std::ffi::CString::new(
+    pub fn from_file(filename: impl AsRef<std::path::Path>) -> Result<Self> {
+        Self::parse_file(filename.as_ref(), None)
+    }
+
+    /// Parse an XML file, resolving the file and its assets through the given VFS first
+    pub fn from_file_with_vfs(
+        filename: impl AsRef<std::path::Path>,
+        vfs: &crate::Vfs,
+    ) -> Result<Self> {
+        Self::parse_file(filename.as_ref(), Some(vfs))
+    }
+
+    /// Parse an XML string whose referenced assets are resolved through the given VFS
+    pub fn from_str_with_vfs(xml: &str, vfs: &crate::Vfs) -> Result<Self> {
+        Self::parse_str(xml, Some(vfs))
+    }
+
+    pub(crate) fn parse_file(filename: &std::path::Path, vfs: Option<&crate::Vfs>) -> Result<Self> {
+        let c_filename = to_cstring(
             filename
-                .as_ref()
                 .to_str()
-                .ok_or_else(|| ParseError("Path contains invalid UTF-8".into()))?,
-        )
-        .map_err(|e| ParseError(format!("Failed to convert path to CString: {e}")))?;
+                .ok_or_else(|| Error::InvalidName(filename.to_string_lossy().into_owned()))?,
+        )?;
 
         const ERROR_SIZE: usize = 1024;
         let mut error_buf = [0u8; ERROR_SIZE];
@@ -35,24 +37,50 @@ impl Spec {
         let ptr = unsafe {
             mujoco_sys::mj_parseXML(
                 c_filename.as_ptr(),
-                std::ptr::null(),
+                crate::vfs::vfs_ptr(vfs),
                 error_buf.as_mut_ptr() as *mut std::os::raw::c_char,
                 ERROR_SIZE as std::os::raw::c_int,
             )
         };
 
         if ptr.is_null() {
-            let error_msg = unsafe {
-                CStr::from_ptr(error_buf.as_ptr() as *const std::os::raw::c_char)
-                    .to_string_lossy()
-                    .into_owned()
-            };
-            return Err(ParseError(error_msg));
+            return Err(Error::Parse(ErrorInfo::new(
+                error_buf_to_string(&error_buf),
+                Some(filename.to_path_buf()),
+            )));
         }
 
         Ok(Spec { ptr })
     }
 
+    fn parse_str(xml: &str, vfs: Option<&crate::Vfs>) -> Result<Self> {
+        let c_xml = std::ffi::CString::new(xml).map_err(|e| {
+            Error::Parse(ErrorInfo::new(
+                format!("XML contains a NUL byte: {e}"),
+                None,
+            ))
+        })?;
+
+        const ERROR_SIZE: usize = 1024;
+        let mut error_buf = [0u8; ERROR_SIZE];
+
+        let ptr = unsafe {
+            mujoco_sys::mj_parseXMLString(
+                c_xml.as_ptr(),
+                crate::vfs::vfs_ptr(vfs),
+                error_buf.as_mut_ptr() as *mut std::os::raw::c_char,
+                ERROR_SIZE as std::os::raw::c_int,
+            )
+        };
+        if ptr.is_null() {
+            return Err(Error::Parse(ErrorInfo::new(
+                error_buf_to_string(&error_buf),
+                None,
+            )));
+        }
+        Ok(Spec { ptr })
+    }
+
     /// Get the raw pointer (for FFI calls)
     pub fn as_ptr(&self) -> *mut mujoco_sys::mjSpec {
         self.ptr
@@ -63,42 +91,149 @@ impl Spec {
         unsafe { &*self.ptr }
     }
 
-    pub fn compile(self) -> crate::Model {
-        let ptr = unsafe { mujoco_sys::mj_compile(self.ptr, std::ptr::null()) };
+    /// Compile the spec into a model
+    ///
+    /// The spec is left intact, so it can be edited and compiled again.
+    /// Compiler warnings do not fail compilation; they are logged and can be
+    /// retrieved with [`Spec::warning`].
+    pub fn compile(&self) -> Result<crate::Model> {
+        self.compile_vfs(None)
+    }
+
+    /// Compile the spec, loading mesh, texture and other assets through the given VFS
+    pub fn compile_with_vfs(&self, vfs: &crate::Vfs) -> Result<crate::Model> {
+        self.compile_vfs(Some(vfs))
+    }
+
+    pub(crate) fn compile_vfs(&self, vfs: Option<&crate::Vfs>) -> Result<crate::Model> {
+        let ptr = crate::handlers::guard(|| unsafe {
+            mujoco_sys::mj_compile(self.ptr, crate::vfs::vfs_ptr(vfs))
+        })?;
         if ptr.is_null() {
-            panic!("Failed to compile mjSpec into mjModel");
+            return Err(Error::Compile(ErrorInfo::new(self.last_message(), None)));
+        }
+        if let Some(warning) = self.warning() {
+            log::warn!(target: "mujoco", "{warning}");
         }
-        crate::Model { ptr }
+        crate::Model::from_raw(ptr)
     }
-}
 
-impl FromStr for Spec {
-    type Err = ParseError;
+    /// Apply the edits made to this spec to `model` in place, keeping the state in `data`
+    ///
+    /// MuJoCo carries the time and the `qpos`, `qvel`, `act`, `ctrl` and mocap state of
+    /// elements that exist both before and after the edit over to the new layout, even
+    /// if their addresses change. Newly added elements start from their defaults.
+    /// Reattach `data` to the model with [`crate::DetachedData::attach`] afterwards.
+    /// Any other data created for the model keeps the old layout and can no longer be
+    /// attached to it.
+    ///
+    /// Returns [`Error::ModelMismatch`] if `data` was created for a different model.
+    pub fn recompile(
+        &mut self,
+        model: &mut crate::Model,
+        data: &mut crate::DetachedData,
+    ) -> Result<()> {
+        self.recompile_vfs(model, data, None)
+    }
 
-    fn from_str(xml: &str) -> Result<Self, Self::Err> {
-        let c_xml = std::ffi::CString::new(xml)
-            .map_err(|e| ParseError(format!("Failed to convert XML to CString: '{e}'")))?;
+    /// Like [`Spec::recompile`], resolving assets through the given VFS first
+    pub fn recompile_with_vfs(
+        &mut self,
+        model: &mut crate::Model,
+        data: &mut crate::DetachedData,
+        vfs: &crate::Vfs,
+    ) -> Result<()> {
+        self.recompile_vfs(model, data, Some(vfs))
+    }
+
+    fn recompile_vfs(
+        &mut self,
+        model: &mut crate::Model,
+        data: &mut crate::DetachedData,
+        vfs: Option<&crate::Vfs>,
+    ) -> Result<()> {
+        data.check_model(model)?;
+        let result = crate::handlers::guard(|| unsafe {
+            mujoco_sys::mj_recompile(self.ptr, crate::vfs::vfs_ptr(vfs), model.ptr, data.ptr)
+        })?;
+        if result != 0 {
+            return Err(Error::Compile(ErrorInfo::new(self.last_message(), None)));
+        }
+        model.renew_id();
+        data.rebind(model);
+        model.check_enum_fields()
+    }
+
+    /// Warning reported by the most recent compilation, if any
+    pub fn warning(&self) -> Option<String> {
+        let is_warning = unsafe { mujoco_sys::mjs_isWarning(self.ptr) } != 0;
+        let message = self.last_message();
+        (is_warning && !message.is_empty()).then_some(message)
+    }
+
+    /// Serialize the spec to an MJCF XML string
+    ///
+    /// MuJoCo can only write compiled specs, so the spec is compiled first. Edits made
+    /// since the last compilation are included, and a spec that does not compile
+    /// returns [`Error::Compile`]. Assets are loaded from the file system; use
+    /// [`Spec::to_xml_string_with_vfs`] if they live in a VFS.
+    pub fn to_xml_string(&self) -> Result<String> {
+        self.xml_string_vfs(None)
+    }
+
+    /// Like [`Spec::to_xml_string`], resolving assets through the given VFS first
+    pub fn to_xml_string_with_vfs(&self, vfs: &crate::Vfs) -> Result<String> {
+        self.xml_string_vfs(Some(vfs))
+    }
+
+    fn xml_string_vfs(&self, vfs: Option<&crate::Vfs>) -> Result<String> {
+        self.compile_vfs(vfs)?;
 
         const ERROR_SIZE: usize = 1024;
         let mut error_buf = [0u8; ERROR_SIZE];
+        let mut xml_buf = vec![0u8; 1 << 16];
 
-        let ptr = unsafe {
-            mujoco_sys::mj_parseXMLString(
-                c_xml.as_ptr(),
-                std::ptr::null(),
-                error_buf.as_mut_ptr() as *mut std::os::raw::c_char,
-                ERROR_SIZE as std::os::raw::c_int,
-            )
-        };
-        if ptr.is_null() {
-            let error_msg = unsafe {
-                CStr::from_ptr(error_buf.as_ptr() as *const std::os::raw::c_char)
-                    .to_string_lossy()
-                    .into_owned()
-            };
-            return Err(ParseError(error_msg));
+        loop {
+            let xml_size = std::os::raw::c_int::try_from(xml_buf.len()).map_err(|_| {
+                Error::InvalidBuffer(format!(
+                    "{} bytes exceeds the XML size limit",
+                    xml_buf.len()
+                ))
+            })?;
+            let result = crate::handlers::guard(|| unsafe {
+                mujoco_sys::mj_saveXMLString(
+                    self.ptr,
+                    xml_buf.as_mut_ptr() as *mut std::os::raw::c_char,
+                    xml_size,
+                    error_buf.as_mut_ptr() as *mut std::os::raw::c_char,
+                    ERROR_SIZE as std::os::raw::c_int,
+                )
+            })?;
+            match result {
+                0 => return Ok(error_buf_to_string(&xml_buf)),
+                // The buffer was too small, MuJoCo reports the required size
+                size if size > 0 => xml_buf.resize(size as usize + 1, 0),
+                _ => return Err(Error::Engine(error_buf_to_string(&error_buf))),
+            }
         }
-        Ok(Spec { ptr })
+    }
+
+    /// Write the spec to an MJCF XML file, compiling it first like [`Spec::to_xml_string`]
+    pub fn save_xml(&self, filename: impl AsRef<std::path::Path>) -> Result<()> {
+        std::fs::write(filename, self.to_xml_string()?)?;
+        Ok(())
+    }
+
+    pub(crate) fn last_message(&self) -> String {
+        unsafe { crate::spec_elements::spec_error(self.ptr) }
+    }
+}
+
+impl FromStr for Spec {
+    type Err = Error;
+
+    fn from_str(xml: &str) -> Result<Self, Self::Err> {
+        Self::parse_str(xml, None)
     }
 }
 
@@ -118,7 +253,7 @@ mod tests {
     fn test_spec_from_str() {
         let spec = Spec::from_str(crate::tests::test_xml_str());
         assert!(spec.is_ok());
-        let model = spec.unwrap().compile();
+        let model = spec.unwrap().compile().unwrap();
         assert_ne!(model.as_ptr(), std::ptr::null());
         assert!(model.nq() == 3);
         assert!(model.nu() == 3);
@@ -130,11 +265,138 @@ mod tests {
         assert!(spec.is_err());
     }
 
+    #[test]
+    fn test_spec_from_str_with_vfs() {
+        let xml = r#"
+            <mujoco>
+              <asset>
+                <mesh name="tetra" file="tetra.obj"/>
+              </asset>
+              <worldbody>
+                <geom type="mesh" mesh="tetra"/>
+              </worldbody>
+            </mujoco>"#;
+        let obj = b"v 0 0 0\nv 1 0 0\nv 0 1 0\nv 0 0 1\nf 1 3 2\nf 1 2 4\nf 1 4 3\nf 2 3 4\n";
+
+        let mut vfs = crate::Vfs::new();
+        vfs.add_buffer("tetra.obj", obj).unwrap();
+        let model = Spec::from_str_with_vfs(xml, &vfs)
+            .unwrap()
+            .compile_with_vfs(&vfs)
+            .unwrap();
+        assert_eq!(model.nmesh(), 1);
+    }
+
+    #[test]
+    fn test_spec_compile_invalid() {
+        let spec = Spec::from_str(
+            r#"<mujoco><worldbody><geom type="mesh" mesh="missing"/></worldbody></mujoco>"#,
+        )
+        .unwrap();
+        match spec.compile() {
+            Err(Error::Compile(info)) => assert!(info.message.contains("missing")),
+            other => panic!("expected compile error, got {other:?}"),
+        }
+        assert!(spec.warning().is_none());
+    }
+
+    #[test]
+    fn test_spec_compile_repeatedly() {
+        let spec = Spec::from_str(crate::tests::test_xml_str()).unwrap();
+        let first = spec.compile().unwrap();
+        let second = spec.compile().unwrap();
+        assert_eq!(first.nq(), second.nq());
+        assert!(spec.warning().is_none());
+    }
+
+    #[test]
+    fn test_spec_xml_roundtrip() {
+        let spec = Spec::from_str(crate::tests::test_xml_str()).unwrap();
+        let model = spec.compile().unwrap();
+        let xml = spec.to_xml_string().unwrap();
+        assert!(xml.contains("rrr_robot"));
+        assert_eq!(
+            Spec::from_str(&xml).unwrap().compile().unwrap().nq(),
+            model.nq()
+        );
+
+        let path = std::env::temp_dir().join(format!("mujoco-rs-{}.xml", std::process::id()));
+        spec.save_xml(&path).unwrap();
+        let loaded = Spec::from_file(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.unwrap().compile().unwrap().nbody(), model.nbody());
+
+        let missing_dir = std::env::temp_dir()
+            .join("mujoco-rs-missing")
+            .join("model.xml");
+        assert!(matches!(spec.save_xml(missing_dir), Err(Error::Io(_))));
+    }
+
+    #[test]
+    fn test_spec_save_uncompiled() {
+        let mut spec = Spec::from_str(crate::tests::test_xml_str()).unwrap();
+        spec.body("end_effector")
+            .unwrap()
+            .add_geom()
+            .unwrap()
+            .set_size(&crate::Vec3::new(0.01, 0.0, 0.0));
+        let xml = spec.to_xml_string().unwrap();
+        assert_eq!(Spec::from_str(&xml).unwrap().compile().unwrap().ngeom(), 6);
+
+        spec.body("end_effector")
+            .unwrap()
+            .add_geom()
+            .unwrap()
+            .set_type(mujoco_sys::mjtGeom::MESH)
+            .set_mesh("missing")
+            .unwrap();
+        assert!(matches!(spec.to_xml_string(), Err(Error::Compile(_))));
+    }
+
+    #[test]
+    fn test_spec_recompile_keeps_state() {
+        let mut spec = Spec::from_str(crate::tests::test_xml_str()).unwrap();
+        let mut model = spec.compile().unwrap();
+        let mut data = crate::Data::new(&model);
+        data.qpos_mut().copy_from_slice(&[0.1, 0.2, 0.3]);
+        data.qvel_mut().copy_from_slice(&[1.0, 2.0, 3.0]);
+        data.raw_mut().time = 1.5;
+
+        // A new joint ahead of joint1 shifts the addresses of all existing joints
+        spec.body("base_link")
+            .unwrap()
+            .add_joint()
+            .unwrap()
+            .set_type(mujoco_sys::mjtJoint::SLIDE);
+        spec.body("end_effector")
+            .unwrap()
+            .add_geom()
+            .unwrap()
+            .set_size(&crate::Vec3::new(0.01, 0.0, 0.0));
+
+        let mut detached = data.detach();
+        let stale = crate::Data::new(&model).detach();
+        spec.recompile(&mut model, &mut detached).unwrap();
+        // Only the recompiled data matches the new layout
+        assert!(matches!(stale.attach(&model), Err(Error::ModelMismatch(_))));
+        let data = detached.attach(&model).unwrap();
+        assert_eq!(model.nq(), 4);
+        assert_eq!(model.ngeom(), 6);
+        assert_eq!(data.time(), 1.5);
+        let joint3 = crate::name2id(&model, mujoco_sys::mjtObj::JOINT, "joint3").unwrap();
+        let adr = model.jnt_qposadr()[joint3 as usize] as usize;
+        assert_eq!(data.qpos()[adr], 0.3);
+        assert_eq!(
+            data.qvel()[model.jnt_dofadr()[joint3 as usize] as usize],
+            3.0
+        );
+    }
+
     #[test]
     fn test_spec_from_file() {
         let spec = Spec::from_file(crate::tests::test_xml_path());
         assert!(spec.is_ok());
-        let model = spec.unwrap().compile();
+        let model = spec.unwrap().compile().unwrap();
         assert_ne!(model.as_ptr(), std::ptr::null());
     }
 }
@@ -0,0 +1,1153 @@
+//! Safe handles for editing the elements of an mjSpec
+//!
+//! Handles borrow the [`Spec`] they belong to mutably, so the borrow checker
+//! guarantees that an element cannot outlive its spec or be aliased by
+//! another handle. Setters return `&mut Self` so calls can be chained.
+
+use crate::error::{Error, Result, to_cstring};
+use crate::{Quat, Spec, Vec3};
+use std::marker::PhantomData;
+
+/// Read an mjString into an owned String
+pub(crate) unsafe fn spec_string(string: *const mujoco_sys::mjString) -> String {
+    if string.is_null() {
+        return String::new();
+    }
+    unsafe {
+        let ptr = mujoco_sys::mjs_getString(string);
+        if ptr.is_null() {
+            String::new()
+        } else {
+            std::ffi::CStr::from_ptr(ptr).to_string_lossy().into_owned()
+        }
+    }
+}
+
+/// Overwrite an mjString with a Rust string
+pub(crate) unsafe fn set_spec_string(dest: *mut mujoco_sys::mjString, text: &str) -> Result<()> {
+    let c_text = to_cstring(text)?;
+    unsafe { mujoco_sys::mjs_setString(dest, c_text.as_ptr()) };
+    Ok(())
+}
+
+/// Read an mjDoubleVec into an owned Vec
+unsafe fn spec_doubles(values: *const mujoco_sys::mjDoubleVec) -> Vec<f64> {
+    let mut size = 0;
+    let ptr = unsafe { mujoco_sys::mjs_getDouble(values, &mut size) };
+    if ptr.is_null() || size <= 0 {
+        return Vec::new();
+    }
+    unsafe { std::slice::from_raw_parts(ptr, size as usize) }.to_vec()
+}
+
+/// Overwrite an mjDoubleVec with the given values
+unsafe fn set_spec_doubles(dest: *mut mujoco_sys::mjDoubleVec, values: &[f64]) -> Result<()> {
+    let len = i32::try_from(values.len()).map_err(|_| {
+        Error::InvalidBuffer(format!(
+            "{} values exceed the mjDoubleVec size limit",
+            values.len()
+        ))
+    })?;
+    unsafe { mujoco_sys::mjs_setDouble(dest, values.as_ptr(), len) };
+    Ok(())
+}
+
+/// Last error message reported for `spec`
+pub(crate) unsafe fn spec_error(spec: *mut mujoco_sys::mjSpec) -> String {
+    unsafe {
+        std::ffi::CStr::from_ptr(mujoco_sys::mjs_getError(spec))
+            .to_string_lossy()
+            .into_owned()
+    }
+}
+
+/// Convert the error string returned by the mjs_setTo* family into a Result
+unsafe fn check_set_to(error: *const std::os::raw::c_char) -> Result<()> {
+    if error.is_null() {
+        return Ok(());
+    }
+    let message = unsafe { std::ffi::CStr::from_ptr(error).to_string_lossy() };
+    if message.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::Engine(message.into_owned()))
+    }
+}
+
+fn quat_from_raw(q: &[f64; 4]) -> Quat {
+    // MuJoCo uses (w, x, y, z), nalgebra Quaternion::new takes (w, x, y, z)
+    Quat::from_quaternion(nalgebra::Quaternion::new(q[0], q[1], q[2], q[3]))
+}
+
+fn quat_to_raw(q: &Quat) -> [f64; 4] {
+    [q.w, q.i, q.j, q.k]
+}
+
+/// Operations shared by every spec element
+pub trait Element {
+    /// Get the raw element pointer (for FFI calls)
+    fn element_ptr(&self) -> *mut mujoco_sys::mjsElement;
+
+    /// Name of the element, empty if it is unnamed
+    fn name(&self) -> String {
+        unsafe { spec_string(mujoco_sys::mjs_getName(self.element_ptr())) }
+    }
+
+    /// Rename the element
+    fn set_name(&mut self, name: &str) -> Result<&mut Self>
+    where
+        Self: Sized,
+    {
+        let c_name = to_cstring(name)?;
+        let element = self.element_ptr();
+        let result = crate::handlers::guard(|| unsafe {
+            mujoco_sys::mjs_setName(element, c_name.as_ptr())
+        })?;
+        if result != 0 {
+            let spec = unsafe { mujoco_sys::mjs_getSpec(element) };
+            let existing =
+                unsafe { mujoco_sys::mjs_findElement(spec, self.obj_type(), c_name.as_ptr()) };
+            if !existing.is_null() && existing != element {
+                return Err(Error::DuplicateName(name.to_owned()));
+            }
+            return Err(Error::Engine(unsafe { spec_error(spec) }));
+        }
+        Ok(self)
+    }
+
+    /// Index of the element in the most recently compiled model, if any
+    fn id(&self) -> Option<usize> {
+        let id = unsafe { mujoco_sys::mjs_getId(self.element_ptr()) };
+        usize::try_from(id).ok()
+    }
+
+    /// Object type of the element
+    fn obj_type(&self) -> mujoco_sys::mjtObj {
+        unsafe { (*self.element_ptr()).elemtype }
+    }
+}
+
+macro_rules! spec_handle {
+    ($(#[$doc:meta])* $name:ident, $raw:ty) => {
+        $(#[$doc])*
+        pub struct $name<'s> {
+            ptr: *mut $raw,
+            _spec: PhantomData<&'s mut Spec>,
+        }
+
+        impl<'s> $name<'s> {
+            pub(crate) fn from_raw(ptr: *mut $raw) -> Option<Self> {
+                (!ptr.is_null()).then_some(Self {
+                    ptr,
+                    _spec: PhantomData,
+                })
+            }
+
+            /// Get the raw pointer (for FFI calls)
+            pub fn as_mut_ptr(&mut self) -> *mut $raw {
+                self.ptr
+            }
+
+            /// Get immutable reference to the raw element
+            pub fn raw(&self) -> &$raw {
+                unsafe { &*self.ptr }
+            }
+
+            /// Get mutable reference to the raw element
+            pub fn raw_mut(&mut self) -> &mut $raw {
+                unsafe { &mut *self.ptr }
+            }
+
+            /// Remove the element (and its children) from the spec
+            pub fn delete(self) -> Result<()> {
+                let element = self.element_ptr();
+                let result = unsafe {
+                    mujoco_sys::mjs_delete(mujoco_sys::mjs_getSpec(element), element)
+                };
+                if result != 0 {
+                    return Err(Error::Engine(format!("Failed to delete '{}'", self.name())));
+                }
+                Ok(())
+            }
+        }
+
+        impl Element for $name<'_> {
+            fn element_ptr(&self) -> *mut mujoco_sys::mjsElement {
+                self.raw().element
+            }
+        }
+    };
+}
+
+macro_rules! spec_pose {
+    ($name:ident) => {
+        impl $name<'_> {
+            /// Position relative to the parent frame
+            pub fn pos(&self) -> Vec3 {
+                Vec3::from(self.raw().pos)
+            }
+
+            /// Set position relative to the parent frame
+            pub fn set_pos(&mut self, pos: &Vec3) -> &mut Self {
+                self.raw_mut().pos = (*pos).into();
+                self
+            }
+        }
+    };
+    ($name:ident, quat) => {
+        spec_pose!($name);
+
+        impl $name<'_> {
+            /// Orientation relative to the parent frame
+            pub fn quat(&self) -> Quat {
+                quat_from_raw(&self.raw().quat)
+            }
+
+            /// Set orientation relative to the parent frame
+            ///
+            /// This replaces any alternative orientation (axis-angle, euler, ...)
+            /// the element was parsed with.
+            pub fn set_quat(&mut self, quat: &Quat) -> &mut Self {
+                let raw = self.raw_mut();
+                raw.quat = quat_to_raw(quat);
+                raw.alt.type_ = mujoco_sys::mjtOrientation::QUAT;
+                self
+            }
+        }
+    };
+}
+
+spec_handle!(
+    /// Handle to a body in an mjSpec
+    BodySpec,
+    mujoco_sys::mjsBody
+);
+spec_handle!(
+    /// Handle to a geom in an mjSpec
+    GeomSpec,
+    mujoco_sys::mjsGeom
+);
+spec_handle!(
+    /// Handle to a joint in an mjSpec
+    JointSpec,
+    mujoco_sys::mjsJoint
+);
+spec_handle!(
+    /// Handle to a site in an mjSpec
+    SiteSpec,
+    mujoco_sys::mjsSite
+);
+spec_handle!(
+    /// Handle to a camera in an mjSpec
+    CameraSpec,
+    mujoco_sys::mjsCamera
+);
+spec_handle!(
+    /// Handle to a light in an mjSpec
+    LightSpec,
+    mujoco_sys::mjsLight
+);
+spec_handle!(
+    /// Handle to an actuator in an mjSpec
+    ActuatorSpec,
+    mujoco_sys::mjsActuator
+);
+spec_handle!(
+    /// Handle to a sensor in an mjSpec
+    SensorSpec,
+    mujoco_sys::mjsSensor
+);
+spec_handle!(
+    /// Handle to a keyframe in an mjSpec
+    KeyframeSpec,
+    mujoco_sys::mjsKey
+);
+spec_handle!(
+    /// Handle to a coordinate frame in an mjSpec
+    FrameSpec,
+    mujoco_sys::mjsFrame
+);
+
+spec_pose!(BodySpec, quat);
+spec_pose!(GeomSpec, quat);
+spec_pose!(JointSpec);
+spec_pose!(SiteSpec, quat);
+spec_pose!(CameraSpec, quat);
+spec_pose!(LightSpec);
+spec_pose!(FrameSpec, quat);
+
+impl<'s> BodySpec<'s> {
+    /// Add a child body
+    pub fn add_body(&mut self) -> Result<BodySpec<'_>> {
+        let ptr = crate::handlers::guard(|| unsafe {
+            mujoco_sys::mjs_addBody(self.ptr, std::ptr::null())
+        })?;
+        BodySpec::from_raw(ptr).ok_or(Error::Allocation("mjsBody"))
+    }
+
+    /// Add a geom attached to this body
+    pub fn add_geom(&mut self) -> Result<GeomSpec<'_>> {
+        let ptr = crate::handlers::guard(|| unsafe {
+            mujoco_sys::mjs_addGeom(self.ptr, std::ptr::null())
+        })?;
+        GeomSpec::from_raw(ptr).ok_or(Error::Allocation("mjsGeom"))
+    }
+
+    /// Add a joint connecting this body to its parent
+    pub fn add_joint(&mut self) -> Result<JointSpec<'_>> {
+        let ptr = crate::handlers::guard(|| unsafe {
+            mujoco_sys::mjs_addJoint(self.ptr, std::ptr::null())
+        })?;
+        JointSpec::from_raw(ptr).ok_or(Error::Allocation("mjsJoint"))
+    }
+
+    /// Add a free joint, making this body floating
+    pub fn add_free_joint(&mut self) -> Result<JointSpec<'_>> {
+        let ptr = crate::handlers::guard(|| unsafe { mujoco_sys::mjs_addFreeJoint(self.ptr) })?;
+        JointSpec::from_raw(ptr).ok_or(Error::Allocation("mjsJoint"))
+    }
+
+    /// Add a site attached to this body
+    pub fn add_site(&mut self) -> Result<SiteSpec<'_>> {
+        let ptr = crate::handlers::guard(|| unsafe {
+            mujoco_sys::mjs_addSite(self.ptr, std::ptr::null())
+        })?;
+        SiteSpec::from_raw(ptr).ok_or(Error::Allocation("mjsSite"))
+    }
+
+    /// Add a camera attached to this body
+    pub fn add_camera(&mut self) -> Result<CameraSpec<'_>> {
+        let ptr = crate::handlers::guard(|| unsafe {
+            mujoco_sys::mjs_addCamera(self.ptr, std::ptr::null())
+        })?;
+        CameraSpec::from_raw(ptr).ok_or(Error::Allocation("mjsCamera"))
+    }
+
+    /// Add a light attached to this body
+    pub fn add_light(&mut self) -> Result<LightSpec<'_>> {
+        let ptr = crate::handlers::guard(|| unsafe {
+            mujoco_sys::mjs_addLight(self.ptr, std::ptr::null())
+        })?;
+        LightSpec::from_raw(ptr).ok_or(Error::Allocation("mjsLight"))
+    }
+
+    /// Add a coordinate frame attached to this body
+    pub fn add_frame(&mut self) -> Result<FrameSpec<'_>> {
+        let ptr = crate::handlers::guard(|| unsafe {
+            mujoco_sys::mjs_addFrame(self.ptr, std::ptr::null_mut())
+        })?;
+        FrameSpec::from_raw(ptr).ok_or(Error::Allocation("mjsFrame"))
+    }
+
+    /// Find a direct or indirect child body by name
+    pub fn child(&mut self, name: &str) -> Result<BodySpec<'_>> {
+        let c_name = to_cstring(name)?;
+        let ptr = unsafe { mujoco_sys::mjs_findChild(self.ptr, c_name.as_ptr()) };
+        BodySpec::from_raw(ptr).ok_or_else(|| Error::NotFound(name.to_owned()))
+    }
+
+    /// Body mass (used when inertia is given explicitly)
+    pub fn mass(&self) -> f64 {
+        self.raw().mass
+    }
+
+    /// Set body mass and mark the inertial properties as explicit
+    pub fn set_mass(&mut self, mass: f64) -> &mut Self {
+        let raw = self.raw_mut();
+        raw.mass = mass;
+        raw.explicitinertial = 1;
+        self
+    }
+
+    /// Set the diagonal inertia in the inertial frame
+    pub fn set_inertia(&mut self, inertia: &Vec3) -> &mut Self {
+        let raw = self.raw_mut();
+        raw.inertia = (*inertia).into();
+        raw.explicitinertial = 1;
+        self
+    }
+
+    /// Make this a mocap body, driven by `Data::mocap_pos`/`mocap_quat`
+    pub fn set_mocap(&mut self, mocap: bool) -> &mut Self {
+        self.raw_mut().mocap = mocap as u8;
+        self
+    }
+
+    /// Set gravity compensation (1 cancels gravity for this body)
+    pub fn set_gravcomp(&mut self, gravcomp: f64) -> &mut Self {
+        self.raw_mut().gravcomp = gravcomp;
+        self
+    }
+}
+
+impl GeomSpec<'_> {
+    /// Geometric type
+    pub fn geom_type(&self) -> mujoco_sys::mjtGeom {
+        self.raw().type_
+    }
+
+    /// Set geometric type
+    pub fn set_type(&mut self, geom_type: mujoco_sys::mjtGeom) -> &mut Self {
+        self.raw_mut().type_ = geom_type;
+        self
+    }
+
+    /// Type-specific size parameters
+    pub fn size(&self) -> Vec3 {
+        Vec3::from(self.raw().size)
+    }
+
+    /// Set type-specific size parameters (unused entries are ignored)
+    pub fn set_size(&mut self, size: &Vec3) -> &mut Self {
+        self.raw_mut().size = (*size).into();
+        self
+    }
+
+    /// Set the geom from a capsule/cylinder/box segment between two points
+    pub fn set_fromto(&mut self, from: &Vec3, to: &Vec3) -> &mut Self {
+        self.raw_mut().fromto = [from.x, from.y, from.z, to.x, to.y, to.z];
+        self
+    }
+
+    /// Set the color as RGBA in [0, 1]
+    pub fn set_rgba(&mut self, rgba: [f32; 4]) -> &mut Self {
+        self.raw_mut().rgba = rgba;
+        self
+    }
+
+    /// Set sliding, torsional and rolling friction
+    pub fn set_friction(&mut self, friction: &Vec3) -> &mut Self {
+        self.raw_mut().friction = (*friction).into();
+        self
+    }
+
+    /// Set geom mass, overriding density
+    pub fn set_mass(&mut self, mass: f64) -> &mut Self {
+        self.raw_mut().mass = mass;
+        self
+    }
+
+    /// Set geom density used to compute mass
+    pub fn set_density(&mut self, density: f64) -> &mut Self {
+        self.raw_mut().density = density;
+        self
+    }
+
+    /// Set collision type and affinity bitmasks
+    pub fn set_contype(&mut self, contype: i32, conaffinity: i32) -> &mut Self {
+        let raw = self.raw_mut();
+        raw.contype = contype;
+        raw.conaffinity = conaffinity;
+        self
+    }
+
+    /// Set the visualization group
+    pub fn set_group(&mut self, group: i32) -> &mut Self {
+        self.raw_mut().group = group;
+        self
+    }
+
+    /// Set the mesh asset used by mesh geoms
+    pub fn set_mesh(&mut self, mesh: &str) -> Result<&mut Self> {
+        unsafe { set_spec_string(self.raw_mut().meshname, mesh)? };
+        Ok(self)
+    }
+
+    /// Set the material asset
+    pub fn set_material(&mut self, material: &str) -> Result<&mut Self> {
+        unsafe { set_spec_string(self.raw_mut().material, material)? };
+        Ok(self)
+    }
+}
+
+impl JointSpec<'_> {
+    /// Joint type
+    pub fn joint_type(&self) -> mujoco_sys::mjtJoint {
+        self.raw().type_
+    }
+
+    /// Set joint type
+    pub fn set_type(&mut self, joint_type: mujoco_sys::mjtJoint) -> &mut Self {
+        self.raw_mut().type_ = joint_type;
+        self
+    }
+
+    /// Joint axis in the body frame
+    pub fn axis(&self) -> Vec3 {
+        Vec3::from(self.raw().axis)
+    }
+
+    /// Set joint axis in the body frame
+    pub fn set_axis(&mut self, axis: &Vec3) -> &mut Self {
+        self.raw_mut().axis = (*axis).into();
+        self
+    }
+
+    /// Set joint limits and enable them
+    pub fn set_range(&mut self, min: f64, max: f64) -> &mut Self {
+        let raw = self.raw_mut();
+        raw.range = [min, max];
+        raw.limited = mujoco_sys::mjtLimited::TRUE as i32;
+        self
+    }
+
+    /// Set damping coefficient
+    pub fn set_damping(&mut self, damping: f64) -> &mut Self {
+        self.raw_mut().damping = damping;
+        self
+    }
+
+    /// Set stiffness coefficient
+    pub fn set_stiffness(&mut self, stiffness: f64) -> &mut Self {
+        self.raw_mut().stiffness = stiffness;
+        self
+    }
+
+    /// Set armature inertia
+    pub fn set_armature(&mut self, armature: f64) -> &mut Self {
+        self.raw_mut().armature = armature;
+        self
+    }
+
+    /// Set dry friction loss
+    pub fn set_frictionloss(&mut self, frictionloss: f64) -> &mut Self {
+        self.raw_mut().frictionloss = frictionloss;
+        self
+    }
+}
+
+impl SiteSpec<'_> {
+    /// Geometric type used for visualization
+    pub fn site_type(&self) -> mujoco_sys::mjtGeom {
+        self.raw().type_
+    }
+
+    /// Set geometric type used for visualization
+    pub fn set_type(&mut self, site_type: mujoco_sys::mjtGeom) -> &mut Self {
+        self.raw_mut().type_ = site_type;
+        self
+    }
+
+    /// Type-specific size parameters
+    pub fn size(&self) -> Vec3 {
+        Vec3::from(self.raw().size)
+    }
+
+    /// Set type-specific size parameters
+    pub fn set_size(&mut self, size: &Vec3) -> &mut Self {
+        self.raw_mut().size = (*size).into();
+        self
+    }
+
+    /// Set the color as RGBA in [0, 1]
+    pub fn set_rgba(&mut self, rgba: [f32; 4]) -> &mut Self {
+        self.raw_mut().rgba = rgba;
+        self
+    }
+}
+
+impl CameraSpec<'_> {
+    /// Set vertical field of view in degrees
+    pub fn set_fovy(&mut self, fovy: f64) -> &mut Self {
+        self.raw_mut().fovy = fovy;
+        self
+    }
+
+    /// Set how the camera tracks its body
+    pub fn set_mode(&mut self, mode: mujoco_sys::mjtCamLight) -> &mut Self {
+        self.raw_mut().mode = mode;
+        self
+    }
+}
+
+impl LightSpec<'_> {
+    /// Direction of the light in the body frame
+    pub fn dir(&self) -> Vec3 {
+        Vec3::from(self.raw().dir)
+    }
+
+    /// Set direction of the light in the body frame
+    pub fn set_dir(&mut self, dir: &Vec3) -> &mut Self {
+        self.raw_mut().dir = (*dir).into();
+        self
+    }
+
+    /// Set light type
+    pub fn set_type(&mut self, light_type: mujoco_sys::mjtLightType) -> &mut Self {
+        self.raw_mut().type_ = light_type;
+        self
+    }
+
+    /// Set how the light tracks its body
+    pub fn set_mode(&mut self, mode: mujoco_sys::mjtCamLight) -> &mut Self {
+        self.raw_mut().mode = mode;
+        self
+    }
+
+    /// Set diffuse color
+    pub fn set_diffuse(&mut self, diffuse: [f32; 3]) -> &mut Self {
+        self.raw_mut().diffuse = diffuse;
+        self
+    }
+
+    /// Enable or disable shadow casting
+    pub fn set_castshadow(&mut self, castshadow: bool) -> &mut Self {
+        self.raw_mut().castshadow = castshadow as u8;
+        self
+    }
+}
+
+impl ActuatorSpec<'_> {
+    /// Set the transmission target (e.g. a joint or tendon name)
+    pub fn set_target(&mut self, trntype: mujoco_sys::mjtTrn, target: &str) -> Result<&mut Self> {
+        self.raw_mut().trntype = trntype;
+        unsafe { set_spec_string(self.raw_mut().target, target)? };
+        Ok(self)
+    }
+
+    /// Set a scalar transmission gear
+    pub fn set_gear(&mut self, gear: f64) -> &mut Self {
+        self.raw_mut().gear = [gear, 0.0, 0.0, 0.0, 0.0, 0.0];
+        self
+    }
+
+    /// Set control limits and enable them
+    pub fn set_ctrlrange(&mut self, min: f64, max: f64) -> &mut Self {
+        let raw = self.raw_mut();
+        raw.ctrlrange = [min, max];
+        raw.ctrllimited = mujoco_sys::mjtLimited::TRUE as i32;
+        self
+    }
+
+    /// Set force limits and enable them
+    pub fn set_forcerange(&mut self, min: f64, max: f64) -> &mut Self {
+        let raw = self.raw_mut();
+        raw.forcerange = [min, max];
+        raw.forcelimited = mujoco_sys::mjtLimited::TRUE as i32;
+        self
+    }
+
+    /// Configure as a direct-drive motor
+    pub fn set_to_motor(&mut self) -> Result<&mut Self> {
+        unsafe { check_set_to(mujoco_sys::mjs_setToMotor(self.ptr))? };
+        Ok(self)
+    }
+
+    /// Configure as a position servo with gain `kp` and optional damping `kv`
+    pub fn set_to_position(&mut self, kp: f64, kv: Option<f64>) -> Result<&mut Self> {
+        let mut kv = [kv.unwrap_or(0.0)];
+        let mut dampratio = [0.0];
+        let mut timeconst = [0.0];
+        unsafe {
+            check_set_to(mujoco_sys::mjs_setToPosition(
+                self.ptr,
+                kp,
+                kv.as_mut_ptr(),
+                dampratio.as_mut_ptr(),
+                timeconst.as_mut_ptr(),
+                0.0,
+            ))?
+        };
+        Ok(self)
+    }
+
+    /// Configure as a velocity servo with gain `kv`
+    pub fn set_to_velocity(&mut self, kv: f64) -> Result<&mut Self> {
+        unsafe { check_set_to(mujoco_sys::mjs_setToVelocity(self.ptr, kv))? };
+        Ok(self)
+    }
+}
+
+impl SensorSpec<'_> {
+    /// Sensor type
+    pub fn sensor_type(&self) -> mujoco_sys::mjtSensor {
+        self.raw().type_
+    }
+
+    /// Set sensor type
+    pub fn set_type(&mut self, sensor_type: mujoco_sys::mjtSensor) -> &mut Self {
+        self.raw_mut().type_ = sensor_type;
+        self
+    }
+
+    /// Set the object the sensor is attached to
+    pub fn set_object(&mut self, objtype: mujoco_sys::mjtObj, name: &str) -> Result<&mut Self> {
+        self.raw_mut().objtype = objtype;
+        unsafe { set_spec_string(self.raw_mut().objname, name)? };
+        Ok(self)
+    }
+
+    /// Set the reference object for frame sensors
+    pub fn set_reference(&mut self, reftype: mujoco_sys::mjtObj, name: &str) -> Result<&mut Self> {
+        self.raw_mut().reftype = reftype;
+        unsafe { set_spec_string(self.raw_mut().refname, name)? };
+        Ok(self)
+    }
+
+    /// Set the standard deviation of the sensor noise
+    pub fn set_noise(&mut self, noise: f64) -> &mut Self {
+        self.raw_mut().noise = noise;
+        self
+    }
+
+    /// Set the cutoff for real and positive sensor data (0 disables it)
+    pub fn set_cutoff(&mut self, cutoff: f64) -> &mut Self {
+        self.raw_mut().cutoff = cutoff;
+        self
+    }
+}
+
+impl KeyframeSpec<'_> {
+    /// Simulation time
+    pub fn time(&self) -> f64 {
+        self.raw().time
+    }
+
+    /// Set the simulation time
+    pub fn set_time(&mut self, time: f64) -> &mut Self {
+        self.raw_mut().time = time;
+        self
+    }
+
+    /// Position coordinates, empty to use the model defaults
+    pub fn qpos(&self) -> Vec<f64> {
+        unsafe { spec_doubles(self.raw().qpos) }
+    }
+
+    /// Set the position coordinates, `nq` values
+    pub fn set_qpos(&mut self, qpos: &[f64]) -> Result<&mut Self> {
+        unsafe { set_spec_doubles(self.raw_mut().qpos, qpos)? };
+        Ok(self)
+    }
+
+    /// Velocities, empty for zero
+    pub fn qvel(&self) -> Vec<f64> {
+        unsafe { spec_doubles(self.raw().qvel) }
+    }
+
+    /// Set the velocities, `nv` values
+    pub fn set_qvel(&mut self, qvel: &[f64]) -> Result<&mut Self> {
+        unsafe { set_spec_doubles(self.raw_mut().qvel, qvel)? };
+        Ok(self)
+    }
+
+    /// Actuator activations, empty for zero
+    pub fn act(&self) -> Vec<f64> {
+        unsafe { spec_doubles(self.raw().act) }
+    }
+
+    /// Set the actuator activations, `na` values
+    pub fn set_act(&mut self, act: &[f64]) -> Result<&mut Self> {
+        unsafe { set_spec_doubles(self.raw_mut().act, act)? };
+        Ok(self)
+    }
+
+    /// Controls, empty for zero
+    pub fn ctrl(&self) -> Vec<f64> {
+        unsafe { spec_doubles(self.raw().ctrl) }
+    }
+
+    /// Set the controls, `nu` values
+    pub fn set_ctrl(&mut self, ctrl: &[f64]) -> Result<&mut Self> {
+        unsafe { set_spec_doubles(self.raw_mut().ctrl, ctrl)? };
+        Ok(self)
+    }
+
+    /// Positions of the mocap bodies, empty to use the body positions
+    pub fn mpos(&self) -> Vec<Vec3> {
+        unsafe { spec_doubles(self.raw().mpos) }
+            .chunks_exact(3)
+            .map(Vec3::from_column_slice)
+            .collect()
+    }
+
+    /// Set the positions of the mocap bodies
+    pub fn set_mpos(&mut self, mpos: &[Vec3]) -> Result<&mut Self> {
+        let values: Vec<f64> = mpos.iter().flat_map(|p| p.iter().copied()).collect();
+        unsafe { set_spec_doubles(self.raw_mut().mpos, &values)? };
+        Ok(self)
+    }
+
+    /// Orientations of the mocap bodies, empty to use the body orientations
+    pub fn mquat(&self) -> Vec<Quat> {
+        unsafe { spec_doubles(self.raw().mquat) }
+            .chunks_exact(4)
+            .map(|q| quat_from_raw(&[q[0], q[1], q[2], q[3]]))
+            .collect()
+    }
+
+    /// Set the orientations of the mocap bodies
+    pub fn set_mquat(&mut self, mquat: &[Quat]) -> Result<&mut Self> {
+        let values: Vec<f64> = mquat.iter().flat_map(quat_to_raw).collect();
+        unsafe { set_spec_doubles(self.raw_mut().mquat, &values)? };
+        Ok(self)
+    }
+}
+
+impl Spec {
+    /// Create an empty spec containing only the world body
+    pub fn new() -> Self {
+        Self::try_new().expect("mj_makeSpec failed")
+    }
+
+    /// Create an empty spec, returning an error instead of panicking on failure
+    pub fn try_new() -> Result<Self> {
+        let ptr = crate::handlers::guard(|| unsafe { mujoco_sys::mj_makeSpec() })?;
+        if ptr.is_null() {
+            return Err(Error::Allocation("mjSpec"));
+        }
+        Ok(Spec { ptr })
+    }
+
+    /// Set the model name
+    pub fn set_model_name(&mut self, name: &str) -> Result<&mut Self> {
+        unsafe { set_spec_string((*self.ptr).modelname, name)? };
+        Ok(self)
+    }
+
+    /// The world body, root of the kinematic tree
+    pub fn worldbody(&mut self) -> BodySpec<'_> {
+        self.body("world").expect("mjSpec has no world body")
+    }
+
+    /// Add an actuator
+    pub fn add_actuator(&mut self) -> Result<ActuatorSpec<'_>> {
+        let ptr = crate::handlers::guard(|| unsafe {
+            mujoco_sys::mjs_addActuator(self.ptr, std::ptr::null())
+        })?;
+        ActuatorSpec::from_raw(ptr).ok_or(Error::Allocation("mjsActuator"))
+    }
+
+    /// Add a sensor
+    pub fn add_sensor(&mut self) -> Result<SensorSpec<'_>> {
+        let ptr = crate::handlers::guard(|| unsafe { mujoco_sys::mjs_addSensor(self.ptr) })?;
+        SensorSpec::from_raw(ptr).ok_or(Error::Allocation("mjsSensor"))
+    }
+
+    /// Add a keyframe
+    pub fn add_keyframe(&mut self) -> Result<KeyframeSpec<'_>> {
+        let ptr = crate::handlers::guard(|| unsafe { mujoco_sys::mjs_addKey(self.ptr) })?;
+        KeyframeSpec::from_raw(ptr).ok_or(Error::Allocation("mjsKey"))
+    }
+
+    /// Find a body by name
+    pub fn body(&mut self, name: &str) -> Result<BodySpec<'_>> {
+        let c_name = to_cstring(name)?;
+        let ptr = unsafe { mujoco_sys::mjs_findBody(self.ptr, c_name.as_ptr()) };
+        BodySpec::from_raw(ptr).ok_or_else(|| Error::NotFound(name.to_owned()))
+    }
+
+    fn find_element(
+        &mut self,
+        obj_type: mujoco_sys::mjtObj,
+        name: &str,
+    ) -> Result<*mut mujoco_sys::mjsElement> {
+        let c_name = to_cstring(name)?;
+        let ptr = unsafe { mujoco_sys::mjs_findElement(self.ptr, obj_type, c_name.as_ptr()) };
+        if ptr.is_null() {
+            return Err(Error::NotFound(name.to_owned()));
+        }
+        Ok(ptr)
+    }
+
+    /// Find a geom by name
+    pub fn geom(&mut self, name: &str) -> Result<GeomSpec<'_>> {
+        let element = self.find_element(mujoco_sys::mjtObj::GEOM, name)?;
+        Ok(GeomSpec::from_raw(unsafe { mujoco_sys::mjs_asGeom(element) }).unwrap())
+    }
+
+    /// Find a joint by name
+    pub fn joint(&mut self, name: &str) -> Result<JointSpec<'_>> {
+        let element = self.find_element(mujoco_sys::mjtObj::JOINT, name)?;
+        Ok(JointSpec::from_raw(unsafe { mujoco_sys::mjs_asJoint(element) }).unwrap())
+    }
+
+    /// Find a site by name
+    pub fn site(&mut self, name: &str) -> Result<SiteSpec<'_>> {
+        let element = self.find_element(mujoco_sys::mjtObj::SITE, name)?;
+        Ok(SiteSpec::from_raw(unsafe { mujoco_sys::mjs_asSite(element) }).unwrap())
+    }
+
+    /// Find a camera by name
+    pub fn camera(&mut self, name: &str) -> Result<CameraSpec<'_>> {
+        let element = self.find_element(mujoco_sys::mjtObj::CAMERA, name)?;
+        Ok(CameraSpec::from_raw(unsafe { mujoco_sys::mjs_asCamera(element) }).unwrap())
+    }
+
+    /// Find a light by name
+    pub fn light(&mut self, name: &str) -> Result<LightSpec<'_>> {
+        let element = self.find_element(mujoco_sys::mjtObj::LIGHT, name)?;
+        Ok(LightSpec::from_raw(unsafe { mujoco_sys::mjs_asLight(element) }).unwrap())
+    }
+
+    /// Find an actuator by name
+    pub fn actuator(&mut self, name: &str) -> Result<ActuatorSpec<'_>> {
+        let element = self.find_element(mujoco_sys::mjtObj::ACTUATOR, name)?;
+        Ok(ActuatorSpec::from_raw(unsafe { mujoco_sys::mjs_asActuator(element) }).unwrap())
+    }
+
+    /// Find a sensor by name
+    pub fn sensor(&mut self, name: &str) -> Result<SensorSpec<'_>> {
+        let element = self.find_element(mujoco_sys::mjtObj::SENSOR, name)?;
+        Ok(SensorSpec::from_raw(unsafe { mujoco_sys::mjs_asSensor(element) }).unwrap())
+    }
+
+    /// Find a keyframe by name
+    pub fn keyframe(&mut self, name: &str) -> Result<KeyframeSpec<'_>> {
+        let element = self.find_element(mujoco_sys::mjtObj::KEY, name)?;
+        Ok(KeyframeSpec::from_raw(unsafe { mujoco_sys::mjs_asKey(element) }).unwrap())
+    }
+
+    /// Find a frame by name
+    pub fn frame(&mut self, name: &str) -> Result<FrameSpec<'_>> {
+        let c_name = to_cstring(name)?;
+        let ptr = unsafe { mujoco_sys::mjs_findFrame(self.ptr, c_name.as_ptr()) };
+        FrameSpec::from_raw(ptr).ok_or_else(|| Error::NotFound(name.to_owned()))
+    }
+
+    /// Copy the body `body` of `child` (with its subtree) into this spec at `at`
+    ///
+    /// Every name in the copied subtree is wrapped in `prefix` and `suffix`, so the
+    /// same part can be attached several times under different prefixes. If a copied
+    /// name collides with an existing one, [`Error::DuplicateName`] is returned with
+    /// the colliding name.
+    pub fn attach_body(
+        &mut self,
+        at: AttachPoint<'_>,
+        child: &Spec,
+        body: &str,
+        prefix: &str,
+        suffix: &str,
+    ) -> Result<BodySpec<'_>> {
+        let c_body = to_cstring(body)?;
+        let child_body = unsafe { mujoco_sys::mjs_findBody(child.ptr, c_body.as_ptr()) };
+        if child_body.is_null() {
+            return Err(Error::NotFound(body.to_owned()));
+        }
+        let element = self.attach(at, unsafe { (*child_body).element }, prefix, suffix)?;
+        Ok(BodySpec::from_raw(unsafe { mujoco_sys::mjs_asBody(element) }).unwrap())
+    }
+
+    /// Copy the frame `frame` of `child` (with its contents) into this spec at `at`
+    ///
+    /// See [`Spec::attach_body`] for how `prefix` and `suffix` are applied.
+    pub fn attach_frame(
+        &mut self,
+        at: AttachPoint<'_>,
+        child: &Spec,
+        frame: &str,
+        prefix: &str,
+        suffix: &str,
+    ) -> Result<FrameSpec<'_>> {
+        let c_frame = to_cstring(frame)?;
+        let child_frame = unsafe { mujoco_sys::mjs_findFrame(child.ptr, c_frame.as_ptr()) };
+        if child_frame.is_null() {
+            return Err(Error::NotFound(frame.to_owned()));
+        }
+        let element = self.attach(at, unsafe { (*child_frame).element }, prefix, suffix)?;
+        Ok(FrameSpec::from_raw(unsafe { mujoco_sys::mjs_asFrame(element) }).unwrap())
+    }
+
+    fn attach(
+        &mut self,
+        at: AttachPoint<'_>,
+        child: *mut mujoco_sys::mjsElement,
+        prefix: &str,
+        suffix: &str,
+    ) -> Result<*mut mujoco_sys::mjsElement> {
+        let parent = match at {
+            AttachPoint::Body(name) => self.body(name)?.element_ptr(),
+            AttachPoint::Frame(name) => self.frame(name)?.element_ptr(),
+            AttachPoint::Site(name) => self.site(name)?.element_ptr(),
+        };
+        let c_prefix = to_cstring(prefix)?;
+        let c_suffix = to_cstring(suffix)?;
+        let element = crate::handlers::guard(|| unsafe {
+            mujoco_sys::mjs_attach(parent, child, c_prefix.as_ptr(), c_suffix.as_ptr())
+        })?;
+        if element.is_null() {
+            let message = self.last_message();
+            return Err(match repeated_name(&message) {
+                Some(name) => Error::DuplicateName(name),
+                None => Error::Engine(message),
+            });
+        }
+        Ok(element)
+    }
+}
+
+/// Name reported by a MuJoCo "repeated name 'x' ..." error, if `message` is one
+fn repeated_name(message: &str) -> Option<String> {
+    let (_, rest) = message.split_once("repeated name")?;
+    let name = rest
+        .split_once('\'')
+        .and_then(|(_, quoted)| quoted.split_once('\''))
+        .map_or(message, |(name, _)| name);
+    Some(name.to_owned())
+}
+
+/// Element of a [`Spec`] that another spec's body or frame can be attached to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttachPoint<'a> {
+    /// Attach as a child of the named body
+    Body(&'a str),
+    /// Attach inside the named frame
+    Frame(&'a str),
+    /// Attach at the pose of the named site
+    Site(&'a str),
+}
+
+impl Default for Spec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mujoco_sys::{mjtGeom, mjtJoint, mjtObj, mjtSensor, mjtTrn};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_build_pendulum() {
+        let mut spec = Spec::new();
+        spec.set_model_name("pendulum").unwrap();
+        {
+            let mut world = spec.worldbody();
+            world
+                .add_geom()
+                .unwrap()
+                .set_type(mjtGeom::PLANE)
+                .set_size(&Vec3::new(1.0, 1.0, 0.1));
+            let mut link = world.add_body().unwrap();
+            link.set_name("link")
+                .unwrap()
+                .set_pos(&Vec3::new(0.0, 0.0, 1.0));
+            link.add_joint()
+                .unwrap()
+                .set_name("hinge")
+                .unwrap()
+                .set_type(mjtJoint::HINGE)
+                .set_axis(&Vec3::y())
+                .set_range(-1.0, 1.0);
+            link.add_geom()
+                .unwrap()
+                .set_type(mjtGeom::CAPSULE)
+                .set_fromto(&Vec3::zeros(), &Vec3::new(0.0, 0.0, -0.5))
+                .set_size(&Vec3::new(0.05, 0.0, 0.0));
+            link.add_site()
+                .unwrap()
+                .set_name("tip")
+                .unwrap()
+                .set_pos(&Vec3::new(0.0, 0.0, -0.5));
+        }
+        spec.add_actuator()
+            .unwrap()
+            .set_name("motor")
+            .unwrap()
+            .set_target(mjtTrn::JOINT, "hinge")
+            .unwrap()
+            .set_to_motor()
+            .unwrap();
+        spec.add_sensor()
+            .unwrap()
+            .set_type(mjtSensor::JOINTPOS)
+            .set_object(mjtObj::JOINT, "hinge")
+            .unwrap();
+
+        let model = spec.compile().unwrap();
+        assert_eq!(model.nbody(), 2);
+        assert_eq!(model.njnt(), 1);
+        assert_eq!(model.nsite(), 1);
+        assert_eq!(model.nu(), 1);
+        assert_eq!(model.nsensordata(), 1);
+        assert_eq!(
+            crate::name2id(&model, mjtObj::SITE, "tip"),
+            spec.site("tip").unwrap().id().map(|id| id as i32)
+        );
+    }
+
+    #[test]
+    fn test_edit_parsed_spec() {
+        let mut spec = Spec::from_str(crate::tests::test_xml_str()).unwrap();
+        assert_eq!(spec.body("link2").unwrap().name(), "link2");
+        assert!(matches!(spec.body("missing"), Err(Error::NotFound(_))));
+
+        spec.joint("joint1").unwrap().set_damping(5.0);
+        let mut ee = spec.body("end_effector").unwrap();
+        ee.add_geom()
+            .unwrap()
+            .set_type(mjtGeom::BOX)
+            .set_size(&Vec3::new(0.01, 0.01, 0.01));
+        let model = spec.compile().unwrap();
+        assert_eq!(model.ngeom(), 6);
+        assert_eq!(model.dof_damping()[0], 5.0);
+    }
+
+    #[test]
+    fn test_set_quat_overrides_alternative_orientation() {
+        let mut spec = Spec::from_str(
+            r#"<mujoco><worldbody>
+                <body name="tilted" euler="0 0 90"><geom size="0.1"/></body>
+            </worldbody></mujoco>"#,
+        )
+        .unwrap();
+        let quat = Quat::from_euler_angles(0.3, 0.0, 0.0);
+        spec.body("tilted").unwrap().set_quat(&quat);
+        let model = spec.compile().unwrap();
+        assert!(model.body("tilted").unwrap().quat().angle_to(&quat) < 1e-9);
+    }
+
+    #[test]
+    fn test_attach_with_prefix() {
+        let gripper = Spec::from_str(
+            r#"<mujoco><worldbody>
+                <body name="hand">
+                    <geom name="palm" type="box" size="0.02 0.02 0.01"/>
+                    <body name="finger" pos="0 0 0.02">
+                        <joint name="slide" type="slide" axis="1 0 0"/>
+                        <geom type="box" size="0.005 0.005 0.02"/>
+                    </body>
+                </body>
+                <frame name="mount"><body name="camera_body"><camera name="eye"/></body></frame>
+            </worldbody></mujoco>"#,
+        )
+        .unwrap();
+        let mut arm = Spec::from_str(crate::tests::test_xml_str()).unwrap();
+        arm.body("end_effector")
+            .unwrap()
+            .add_site()
+            .unwrap()
+            .set_name("flange")
+            .unwrap();
+
+        let hand = arm
+            .attach_body(AttachPoint::Site("flange"), &gripper, "hand", "left_", "")
+            .unwrap();
+        assert_eq!(hand.name(), "left_hand");
+        assert!(matches!(
+            arm.attach_body(AttachPoint::Site("flange"), &gripper, "hand", "left_", ""),
+            Err(Error::DuplicateName(name)) if name.contains("left_hand")
+        ));
+        assert_eq!(
+            repeated_name("repeated name 'left_hand' in body").as_deref(),
+            Some("left_hand")
+        );
+        assert_eq!(repeated_name("mesh file not found"), None);
+        arm.attach_body(AttachPoint::Body("link2"), &gripper, "hand", "right_", "")
+            .unwrap();
+        arm.attach_frame(AttachPoint::Body("world"), &gripper, "mount", "", "_1")
+            .unwrap();
+        assert!(matches!(
+            arm.attach_body(AttachPoint::Frame("missing"), &gripper, "hand", "", ""),
+            Err(Error::NotFound(_))
+        ));
+
+        let model = arm.compile().unwrap();
+        assert_eq!(model.nq(), 5);
+        assert!(crate::name2id(&model, mjtObj::JOINT, "left_slide").is_some());
+        assert!(crate::name2id(&model, mjtObj::GEOM, "right_palm").is_some());
+        assert!(crate::name2id(&model, mjtObj::CAMERA, "eye_1").is_some());
+    }
+}
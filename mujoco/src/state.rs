@@ -0,0 +1,238 @@
+//! Compact snapshots of the simulation state
+//!
+//! A [`State`] holds the subset of `mjData` selected by [`StateFlags`] as a flat
+//! vector, which is much cheaper to save and restore than a full `Data` clone.
+
+use crate::error::{Error, Result};
+use crate::{Data, Model};
+use mujoco_sys::mjtState;
+
+bitflags::bitflags! {
+    /// Components of the simulation state (mjtState)
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize))]
+    pub struct StateFlags: u32 {
+        const TIME = mjtState::TIME as u32;
+        const QPOS = mjtState::QPOS as u32;
+        const QVEL = mjtState::QVEL as u32;
+        const ACT = mjtState::ACT as u32;
+        const HISTORY = mjtState::HISTORY as u32;
+        const WARMSTART = mjtState::WARMSTART as u32;
+        const CTRL = mjtState::CTRL as u32;
+        const QFRC_APPLIED = mjtState::QFRC_APPLIED as u32;
+        const XFRC_APPLIED = mjtState::XFRC_APPLIED as u32;
+        const EQ_ACTIVE = mjtState::EQ_ACTIVE as u32;
+        const MOCAP_POS = mjtState::MOCAP_POS as u32;
+        const MOCAP_QUAT = mjtState::MOCAP_QUAT as u32;
+        const USERDATA = mjtState::USERDATA as u32;
+        const PLUGIN = mjtState::PLUGIN as u32;
+
+        /// Time, positions, velocities and actuator activations
+        const PHYSICS = mjtState::PHYSICS as u32;
+        /// Physics plus plugin state
+        const FULLPHYSICS = mjtState::FULLPHYSICS as u32;
+        /// User inputs: controls, applied forces, mocap poses, equality activation, userdata
+        const USER = mjtState::USER as u32;
+        /// Everything needed to reproduce the next step exactly
+        const INTEGRATION = mjtState::INTEGRATION as u32;
+    }
+}
+
+impl StateFlags {
+    /// Signature passed to MuJoCo, which rejects unknown bits with an error
+    fn sig(self) -> std::os::raw::c_int {
+        (self & Self::all()).bits() as std::os::raw::c_int
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for StateFlags {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let flags: Self = bitflags::serde::deserialize(deserializer)?;
+        Ok(Self::from_bits_truncate(flags.bits()))
+    }
+}
+
+/// Number of values in a state with the given components
+///
+/// Bits outside of [`StateFlags::all`] are ignored.
+pub fn state_size(model: &Model, flags: StateFlags) -> usize {
+    unsafe { mujoco_sys::mj_stateSize(model.as_ptr(), flags.sig()) as usize }
+}
+
+/// Copy the selected state components from `src` to `dst`
+pub fn copy_state(src: &Data, dst: &mut Data, flags: StateFlags) -> Result<()> {
+    check_len(
+        state_size(src.model(), flags),
+        state_size(dst.model(), flags),
+    )?;
+    crate::handlers::guard(|| unsafe {
+        mujoco_sys::mj_copyState(src.model.as_ptr(), src.ptr, dst.ptr, flags.sig())
+    })
+}
+
+fn check_len(len: usize, expected: usize) -> Result<()> {
+    if len != expected {
+        return Err(Error::InvalidBuffer(format!(
+            "state has {len} values but the model expects {expected}"
+        )));
+    }
+    Ok(())
+}
+
+/// Snapshot of the simulation state components selected by [`StateFlags`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    flags: StateFlags,
+    values: Vec<f64>,
+}
+
+impl State {
+    /// Zero-initialized state sized for `model`, to be filled with [`State::capture_from`]
+    pub fn new(model: &Model, flags: StateFlags) -> Self {
+        Self {
+            flags,
+            values: vec![0.0; state_size(model, flags)],
+        }
+    }
+
+    /// Capture the state of `data`
+    pub fn capture(data: &Data, flags: StateFlags) -> Self {
+        let mut state = Self::new(data.model(), flags);
+        state
+            .capture_from(data)
+            .expect("state sized for the same model");
+        state
+    }
+
+    /// Overwrite this state with the state of `data`, reusing the allocation
+    pub fn capture_from(&mut self, data: &Data) -> Result<()> {
+        check_len(self.values.len(), state_size(data.model(), self.flags))?;
+        crate::handlers::guard(|| unsafe {
+            mujoco_sys::mj_getState(
+                data.model.as_ptr(),
+                data.ptr,
+                self.values.as_mut_ptr(),
+                self.flags.sig(),
+            )
+        })
+    }
+
+    /// Write this state into `data`, leaving components not in the state untouched
+    pub fn restore(&self, data: &mut Data) -> Result<()> {
+        check_len(self.values.len(), state_size(data.model(), self.flags))?;
+        crate::handlers::guard(|| unsafe {
+            mujoco_sys::mj_setState(
+                data.model.as_ptr(),
+                data.ptr,
+                self.values.as_ptr(),
+                self.flags.sig(),
+            )
+        })
+    }
+
+    /// Extract a subset of the components into a new state
+    pub fn extract(&self, model: &Model, flags: StateFlags) -> Result<State> {
+        check_len(self.values.len(), state_size(model, self.flags))?;
+        if !self.flags.contains(flags) {
+            return Err(Error::InvalidBuffer(format!(
+                "{:?} is not a subset of {:?}",
+                flags, self.flags
+            )));
+        }
+        let mut state = Self::new(model, flags);
+        crate::handlers::guard(|| unsafe {
+            mujoco_sys::mj_extractState(
+                model.as_ptr(),
+                self.values.as_ptr(),
+                self.flags.sig(),
+                state.values.as_mut_ptr(),
+                flags.sig(),
+            )
+        })?;
+        Ok(state)
+    }
+
+    /// Components contained in this state
+    pub fn flags(&self) -> StateFlags {
+        self.flags
+    }
+
+    /// Raw state values, in MuJoCo's mjtState order
+    pub fn as_slice(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Mutable raw state values, in MuJoCo's mjtState order
+    pub fn as_mut_slice(&mut self) -> &mut [f64] {
+        &mut self.values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_roundtrip() {
+        let model = Model::from_file(crate::tests::test_xml_path()).unwrap();
+        let mut data = Data::new(&model);
+        data.qpos_mut().copy_from_slice(&[0.1, 0.2, 0.3]);
+        data.ctrl_mut().copy_from_slice(&[1.0, 1.0, 1.0]);
+        assert_eq!(
+            state_size(&model, StateFlags::PHYSICS),
+            1 + model.nq() + model.nv() + model.na()
+        );
+
+        let state = State::capture(&data, StateFlags::INTEGRATION);
+        for _ in 0..10 {
+            crate::step(&mut data).unwrap();
+        }
+        let stepped = data.qpos().to_vec();
+        state.restore(&mut data).unwrap();
+        assert_eq!(data.qpos(), &[0.1, 0.2, 0.3]);
+        assert_eq!(data.time(), 0.0);
+
+        // Restoring the full integration state reproduces the trajectory exactly
+        for _ in 0..10 {
+            crate::step(&mut data).unwrap();
+        }
+        assert_eq!(data.qpos(), stepped.as_slice());
+
+        let qpos = state.extract(&model, StateFlags::QPOS).unwrap();
+        assert_eq!(qpos.as_slice(), &[0.1, 0.2, 0.3]);
+        assert!(qpos.extract(&model, StateFlags::QVEL).is_err());
+    }
+
+    #[test]
+    fn test_copy_state() {
+        let model = Model::from_file(crate::tests::test_xml_path()).unwrap();
+        let mut src = Data::new(&model);
+        let mut dst = Data::new(&model);
+        src.qpos_mut()[0] = 0.5;
+        src.qvel_mut()[0] = 1.0;
+        copy_state(&src, &mut dst, StateFlags::QPOS).unwrap();
+        assert_eq!(dst.qpos()[0], 0.5);
+        assert_eq!(dst.qvel()[0], 0.0);
+
+        let mut state = State::new(&model, StateFlags::QVEL);
+        state.capture_from(&src).unwrap();
+        assert_eq!(state.as_slice()[0], 1.0);
+    }
+
+    #[test]
+    fn test_unknown_flags_are_ignored() {
+        let model = Model::from_file(crate::tests::test_xml_path()).unwrap();
+        let flags = StateFlags::from_bits_retain(StateFlags::QPOS.bits() | 1 << 30);
+        assert_eq!(state_size(&model, flags), model.nq());
+        let data = Data::new(&model);
+        assert_eq!(State::capture(&data, flags).as_slice().len(), model.nq());
+
+        #[cfg(feature = "serde")]
+        {
+            let flags: StateFlags = serde_json::from_str("\"QPOS | 0x40000000\"").unwrap();
+            assert_eq!(flags, StateFlags::QPOS);
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use crate::error::{Error, Result};
+use std::sync::Arc;
+
+/// Safe Rust wrapper around MuJoCo's thread pool (mjThreadPool)
+///
+/// Bound to one or more `Data` instances with [`crate::Data::bind_thread_pool`], the
+/// pool lets MuJoCo solve constraint islands in parallel (requires the island
+/// option to be enabled). The pool is reference counted: every `Data` bound to
+/// it keeps it alive, so it is only destroyed once the last user is dropped.
+///
+/// MuJoCo errors raised on a worker thread cannot be turned into [`Error::Engine`]
+/// and abort the process, see [`crate::handlers`].
+#[derive(Debug, Clone)]
+pub struct ThreadPool {
+    inner: Arc<RawThreadPool>,
+}
+
+#[derive(Debug)]
+struct RawThreadPool {
+    ptr: *mut mujoco_sys::mjThreadPool,
+}
+
+// Safety: the pool synchronizes access to its queue internally
+unsafe impl Send for RawThreadPool {}
+unsafe impl Sync for RawThreadPool {}
+
+impl ThreadPool {
+    /// Create a pool with `nthread` worker threads
+    pub fn new(nthread: usize) -> Result<Self> {
+        if nthread == 0 || nthread > mujoco_sys::mjMAXTHREAD as usize {
+            return Err(Error::ThreadPool(format!(
+                "thread pool size must be in 1..={}, got {nthread}",
+                mujoco_sys::mjMAXTHREAD
+            )));
+        }
+        let ptr = crate::handlers::guard(|| unsafe { mujoco_sys::mju_threadPoolCreate(nthread) })?;
+        if ptr.is_null() {
+            return Err(Error::Allocation("mjThreadPool"));
+        }
+        Ok(Self {
+            inner: Arc::new(RawThreadPool { ptr }),
+        })
+    }
+
+    /// Number of worker threads
+    pub fn nworker(&self) -> usize {
+        unsafe { (*self.inner.ptr).nworker as usize }
+    }
+
+    /// Get the raw pointer (for FFI calls)
+    pub fn as_ptr(&self) -> *mut mujoco_sys::mjThreadPool {
+        self.inner.ptr
+    }
+}
+
+impl Drop for RawThreadPool {
+    fn drop(&mut self) {
+        unsafe { mujoco_sys::mju_threadPoolDestroy(self.ptr) }
+    }
+}
+
+impl crate::Data<'_> {
+    /// Let MuJoCo use `pool` for multithreaded computations on this data
+    ///
+    /// The data keeps a reference to the pool, so the pool outlives it. A data can
+    /// only be bound once; binding it again returns [`Error::ThreadPool`].
+    pub fn bind_thread_pool(&mut self, pool: &ThreadPool) -> Result<()> {
+        if self.thread_pool.is_some() {
+            return Err(Error::ThreadPool(
+                "data is already bound to a thread pool".into(),
+            ));
+        }
+        crate::handlers::guard(|| unsafe {
+            mujoco_sys::mju_bindThreadPool(self.ptr, pool.as_ptr() as *mut std::os::raw::c_void)
+        })?;
+        self.thread_pool = Some(pool.clone());
+        Ok(())
+    }
+
+    /// Thread pool bound to this data, if any
+    pub fn thread_pool(&self) -> Option<&ThreadPool> {
+        self.thread_pool.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bind_thread_pool() {
+        let model = crate::Model::from_file(crate::tests::test_xml_path()).unwrap();
+        let mut data = crate::Data::new(&model);
+        {
+            let pool = ThreadPool::new(2).unwrap();
+            assert_eq!(pool.nworker(), 2);
+            data.bind_thread_pool(&pool).unwrap();
+        }
+        // The pool is kept alive by the data after the handle is dropped
+        assert_eq!(data.thread_pool().unwrap().nworker(), 2);
+        data.qpos_mut()[0] = 0.3;
+        for _ in 0..10 {
+            crate::step(&mut data).unwrap();
+        }
+        let copy = data.clone();
+        drop(data);
+        assert!(copy.thread_pool().is_some());
+        let mut copy = copy;
+        assert!(matches!(
+            copy.bind_thread_pool(&ThreadPool::new(1).unwrap()),
+            Err(Error::ThreadPool(_))
+        ));
+
+        assert!(matches!(ThreadPool::new(0), Err(Error::ThreadPool(_))));
+    }
+}
@@ -0,0 +1,28 @@
+//! Version compatibility between the bindings and the linked MuJoCo library
+
+use crate::error::{Error, Result};
+
+/// Check that the linked MuJoCo library matches the headers the bindings were generated from
+///
+/// This matters when linking a system-installed MuJoCo, which may be updated independently.
+/// Guarded calls perform this check once and return [`Error::VersionMismatch`] on failure.
+pub fn check_version() -> Result<()> {
+    let library = unsafe { mujoco_sys::mj_version() } as u32;
+    if library != mujoco_sys::mjVERSION_HEADER {
+        return Err(Error::VersionMismatch {
+            header: mujoco_sys::mjVERSION_HEADER,
+            library,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_library_matches_headers() {
+        check_version().unwrap();
+    }
+}
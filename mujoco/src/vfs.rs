@@ -0,0 +1,133 @@
+use crate::error::{Error, Result, to_cstring};
+use std::path::Path;
+
+/// Safe Rust wrapper around MuJoCo's virtual file system (mjVFS)
+///
+/// Buffers added to the VFS are copied by MuJoCo, so the caller's data does not
+/// need to outlive the `Vfs`. All memory is released when the `Vfs` is dropped.
+pub struct Vfs {
+    // Boxed so the address handed to MuJoCo stays stable when the Vfs is moved
+    inner: Box<mujoco_sys::mjVFS>,
+}
+
+// Safety: the VFS is only mutated through &mut self and read through &self
+unsafe impl Send for Vfs {}
+unsafe impl Sync for Vfs {}
+
+impl Vfs {
+    /// Create an empty VFS
+    pub fn new() -> Self {
+        let mut inner = Box::new(mujoco_sys::mjVFS {
+            impl_: std::ptr::null_mut(),
+        });
+        unsafe { mujoco_sys::mj_defaultVFS(inner.as_mut()) };
+        Self { inner }
+    }
+
+    /// Add an in-memory file (e.g. from `include_bytes!`) under the given name
+    pub fn add_buffer(&mut self, name: &str, buffer: &[u8]) -> Result<()> {
+        let c_name = to_cstring(name)?;
+        let size = std::os::raw::c_int::try_from(buffer.len()).map_err(|_| {
+            Error::InvalidBuffer(format!("{} bytes exceeds the VFS size limit", buffer.len()))
+        })?;
+        let result = unsafe {
+            mujoco_sys::mj_addBufferVFS(
+                self.inner.as_mut(),
+                c_name.as_ptr(),
+                buffer.as_ptr() as *const std::os::raw::c_void,
+                size,
+            )
+        };
+        match result {
+            0 => Ok(()),
+            2 => Err(Error::DuplicateName(name.to_owned())),
+            _ => Err(Error::InvalidName(name.to_owned())),
+        }
+    }
+
+    /// Read `directory/filename` from disk and add it to the VFS as `filename`
+    pub fn add_file(&mut self, directory: impl AsRef<Path>, filename: &str) -> Result<()> {
+        let c_directory = to_cstring(&directory.as_ref().to_string_lossy())?;
+        let c_filename = to_cstring(filename)?;
+        let result = unsafe {
+            mujoco_sys::mj_addFileVFS(
+                self.inner.as_mut(),
+                c_directory.as_ptr(),
+                c_filename.as_ptr(),
+            )
+        };
+        match result {
+            0 => Ok(()),
+            2 => Err(Error::DuplicateName(filename.to_owned())),
+            _ => Err(Error::NotFound(filename.to_owned())),
+        }
+    }
+
+    /// Remove a file from the VFS
+    pub fn delete_file(&mut self, filename: &str) -> Result<()> {
+        let c_filename = to_cstring(filename)?;
+        let result =
+            unsafe { mujoco_sys::mj_deleteFileVFS(self.inner.as_mut(), c_filename.as_ptr()) };
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(Error::NotFound(filename.to_owned()))
+        }
+    }
+
+    /// Get the raw pointer (for FFI calls)
+    pub fn as_ptr(&self) -> *const mujoco_sys::mjVFS {
+        self.inner.as_ref()
+    }
+}
+
+impl Default for Vfs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for Vfs {
+    fn drop(&mut self) {
+        unsafe { mujoco_sys::mj_deleteVFS(self.inner.as_mut()) }
+    }
+}
+
+/// Convert an optional VFS reference into the pointer MuJoCo expects
+pub(crate) fn vfs_ptr(vfs: Option<&Vfs>) -> *const mujoco_sys::mjVFS {
+    vfs.map_or(std::ptr::null(), Vfs::as_ptr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vfs_add_and_delete() {
+        let mut vfs = Vfs::new();
+        vfs.add_buffer("rrr.xml", crate::tests::test_xml_str().as_bytes())
+            .unwrap();
+        assert!(matches!(
+            vfs.add_buffer("rrr.xml", b""),
+            Err(Error::DuplicateName(name)) if name == "rrr.xml"
+        ));
+        vfs.delete_file("rrr.xml").unwrap();
+        assert!(matches!(
+            vfs.delete_file("rrr.xml"),
+            Err(Error::NotFound(name)) if name == "rrr.xml"
+        ));
+    }
+
+    #[test]
+    fn test_vfs_add_file() {
+        let mut vfs = Vfs::new();
+        let path = crate::tests::test_xml_path();
+        vfs.add_file(path.parent().unwrap(), "rrr.xml").unwrap();
+        assert!(matches!(
+            vfs.add_file(path.parent().unwrap(), "missing.xml"),
+            Err(Error::NotFound(_))
+        ));
+        let model = crate::Model::from_xml_with_vfs("rrr.xml", &vfs).unwrap();
+        assert_eq!(model.nq(), 3);
+    }
+}